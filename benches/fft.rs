@@ -16,7 +16,7 @@ extern crate criterion;
 use criterion::{BenchmarkId, Criterion};
 use group::ff::Field;
 use halo2curves_axiom::bn256::Fr as Scalar;
-use halo2curves_axiom::fft::best_fft;
+use halo2curves_axiom::fft::{best_fft, best_fft_batch};
 use rand_core::OsRng;
 use std::ops::Range;
 use std::time::SystemTime;
@@ -53,5 +53,24 @@ fn fft(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, fft);
+fn fft_batch(c: &mut Criterion) {
+    const NUM_COLUMNS: usize = 16;
+    let max_k = RANGE.max().unwrap_or(16) - 4;
+    let data = generate_data(max_k);
+    let mut columns = vec![data; NUM_COLUMNS];
+    let omega = Scalar::random(OsRng);
+    let mut group = c.benchmark_group("fft_batch");
+    for k in RANGE.filter(|k| *k <= max_k) {
+        group.bench_function(BenchmarkId::new("k", k), |b| {
+            let n = 1 << k;
+            let mut slices: Vec<_> = columns.iter_mut().map(|column| &mut column[..n]).collect();
+            b.iter(|| {
+                best_fft_batch(&mut slices, omega, k);
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, fft, fft_batch);
 criterion_main!(benches);
@@ -0,0 +1,324 @@
+# Reference implementation of the BN254 SvdW hash-to-curve suites
+#   BN254G1_XMD:SHA-256_SVDW_{RO,NU}_
+#   BN254G2_XMD:SHA-256_SVDW_{RO,NU}_
+# following RFC 9380 (https://datatracker.ietf.org/doc/html/rfc9380) to the letter,
+# written separately from the Rust code to produce regression vectors for it.
+#
+# G2 uses `hash_to_field` with m = 2, the `sgn0` of section 4.1 for m = 2, the Z
+# returned by `find_z_svdw` (appendix H.1) and the cofactor clearing of gnark-crypto,
+# `[x]P + psi([3x]P) + psi^2([x]P) + psi^3(P)`.
+#
+# Running the script checks the G1 vectors of gnark-crypto, then prints the G2 ones.
+# The G2 vectors are not checked against an independent implementation, they only
+# pin down the output of the Rust code.
+
+import hashlib
+
+p = 0x30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD47
+r = 0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001
+BN_X = 4965661367192848881
+
+
+# expand_message_xmd with SHA-256, section 5.3.1
+def expand_message_xmd(msg, dst, len_in_bytes):
+    b_in_bytes, s_in_bytes = 32, 64
+    ell = (len_in_bytes + b_in_bytes - 1) // b_in_bytes
+    assert ell <= 255 and len(dst) <= 255
+    dst_prime = dst + bytes([len(dst)])
+    msg_prime = bytes(s_in_bytes) + msg + len_in_bytes.to_bytes(2, "big") + b"\x00" + dst_prime
+    b0 = hashlib.sha256(msg_prime).digest()
+    b = [hashlib.sha256(b0 + b"\x01" + dst_prime).digest()]
+    for i in range(2, ell + 1):
+        prev = bytes(x ^ y for x, y in zip(b0, b[-1]))
+        b.append(hashlib.sha256(prev + bytes([i]) + dst_prime).digest())
+    return b"".join(b)[:len_in_bytes]
+
+
+# hash_to_field, section 5.2, returning `count` lists of `m` integers mod p
+def hash_to_field(msg, dst, count, m):
+    L = (p.bit_length() + 128 + 7) // 8
+    uniform_bytes = expand_message_xmd(msg, dst, count * m * L)
+    out = []
+    for i in range(count):
+        e = []
+        for j in range(m):
+            offset = L * (j + i * m)
+            e.append(int.from_bytes(uniform_bytes[offset : offset + L], "big") % p)
+        out.append(e)
+    return out
+
+
+class Fp:
+    def __init__(self, v):
+        self.v = v % p
+
+    def __add__(self, o):
+        return Fp(self.v + o.v)
+
+    def __sub__(self, o):
+        return Fp(self.v - o.v)
+
+    def __neg__(self):
+        return Fp(-self.v)
+
+    def __mul__(self, o):
+        return Fp(self.v * o.v)
+
+    def __eq__(self, o):
+        return self.v == o.v
+
+    def __pow__(self, e):
+        return Fp(pow(self.v, e, p))
+
+    def inv0(self):
+        return Fp(pow(self.v, p - 2, p))
+
+    def is_zero(self):
+        return self.v == 0
+
+    def is_square(self):
+        return self.v == 0 or pow(self.v, (p - 1) // 2, p) == 1
+
+    def sqrt(self):
+        # p = 3 mod 4
+        s = Fp(pow(self.v, (p + 1) // 4, p))
+        assert s * s == self
+        return s
+
+    def sgn0(self):
+        return self.v % 2
+
+    def frob(self):
+        return self
+
+    @staticmethod
+    def of(v):
+        return Fp(v)
+
+
+class Fp2:
+    # c0 + c1 * u with u^2 = -1
+    def __init__(self, c0, c1=0):
+        self.c0, self.c1 = c0 % p, c1 % p
+
+    def __add__(self, o):
+        return Fp2(self.c0 + o.c0, self.c1 + o.c1)
+
+    def __sub__(self, o):
+        return Fp2(self.c0 - o.c0, self.c1 - o.c1)
+
+    def __neg__(self):
+        return Fp2(-self.c0, -self.c1)
+
+    def __mul__(self, o):
+        return Fp2(self.c0 * o.c0 - self.c1 * o.c1, self.c0 * o.c1 + self.c1 * o.c0)
+
+    def __eq__(self, o):
+        return self.c0 == o.c0 and self.c1 == o.c1
+
+    def __pow__(self, e):
+        acc, base = Fp2(1), self
+        while e:
+            if e & 1:
+                acc = acc * base
+            base = base * base
+            e >>= 1
+        return acc
+
+    def norm(self):
+        return (self.c0 * self.c0 + self.c1 * self.c1) % p
+
+    def inv0(self):
+        if self.is_zero():
+            return Fp2(0)
+        n = pow(self.norm(), p - 2, p)
+        return Fp2(self.c0 * n, -self.c1 * n)
+
+    def is_zero(self):
+        return self.c0 == 0 and self.c1 == 0
+
+    def is_square(self):
+        n = self.norm()
+        return n == 0 or pow(n, (p - 1) // 2, p) == 1
+
+    def sqrt(self):
+        # Algorithm 9 of https://eprint.iacr.org/2012/685, for p = 3 mod 4
+        a1 = self ** ((p - 3) // 4)
+        alpha = a1 * a1 * self
+        x0 = a1 * self
+        if alpha == Fp2(-1):
+            x = Fp2(0, 1) * x0
+        else:
+            x = (Fp2(1) + alpha) ** ((p - 1) // 2) * x0
+        assert x * x == self
+        return x
+
+    def sgn0(self):
+        # section 4.1 with m = 2
+        sign_0 = self.c0 % 2
+        zero_0 = self.c0 == 0
+        sign_1 = self.c1 % 2
+        return sign_0 | (zero_0 and sign_1)
+
+    def frob(self):
+        return Fp2(self.c0, -self.c1)
+
+    @staticmethod
+    def of(v):
+        return Fp2(v)
+
+
+def find_z_svdw(F, A, B):
+    # appendix H.1
+    g = lambda x: x * x * x + A * x + B
+    h = lambda Z: -(F.of(3) * Z * Z + F.of(4) * A) * (F.of(4) * g(Z)).inv0()
+    ctr = 1
+    while True:
+        for Z in (F.of(ctr), F.of(-ctr)):
+            if g(Z).is_zero() or h(Z).is_zero() or not h(Z).is_square():
+                continue
+            if g(Z).is_square() or g(-Z * F.of(2).inv0()).is_square():
+                return Z
+        ctr += 1
+
+
+class SvdW:
+    # section 6.6.1 with the constants of its straight-line implementation in F.1
+    def __init__(self, F, A, B):
+        self.F, self.A, self.B = F, A, B
+        self.Z = Z = find_z_svdw(F, A, B)
+        g = lambda x: x * x * x + A * x + B
+        tmp = F.of(3) * Z * Z + F.of(4) * A
+        self.c1 = g(Z)
+        self.c2 = -Z * F.of(2).inv0()
+        c3 = (-g(Z) * tmp).sqrt()
+        self.c3 = -c3 if c3.sgn0() == 1 else c3
+        self.c4 = -F.of(4) * g(Z) * tmp.inv0()
+
+    def g(self, x):
+        return x * x * x + self.A * x + self.B
+
+    def map(self, u):
+        F, one = self.F, self.F.of(1)
+        tv1 = u * u * self.c1
+        tv2 = one + tv1
+        tv1 = one - tv1
+        tv3 = (tv1 * tv2).inv0()
+        tv4 = u * tv1 * tv3 * self.c3
+        x1 = self.c2 - tv4
+        x2 = self.c2 + tv4
+        x3 = tv2 * tv2 * tv3
+        x3 = x3 * x3 * self.c4 + self.Z
+        if self.g(x1).is_square():
+            x = x1
+        elif self.g(x2).is_square():
+            x = x2
+        else:
+            x = x3
+        y = self.g(x).sqrt()
+        if u.sgn0() != y.sgn0():
+            y = -y
+        return (x, y)
+
+
+# affine arithmetic, None is the point at infinity
+def add(P, Q):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    (x1, y1), (x2, y2) = P, Q
+    if x1 == x2:
+        if (y1 + y2).is_zero():
+            return None
+        lam = (x1 * x1 * x1.of(3)) * (y1 + y1).inv0()
+    else:
+        lam = (y2 - y1) * (x2 - x1).inv0()
+    x3 = lam * lam - x1 - x2
+    return (x3, lam * (x1 - x3) - y1)
+
+
+def mul(k, P):
+    acc = None
+    for bit in bin(k)[2:]:
+        acc = add(acc, acc)
+        if bit == "1":
+            acc = add(acc, P)
+    return acc
+
+
+XI = Fp2(9, 1)
+PSI_X = XI ** ((p - 1) // 3)
+PSI_Y = XI ** ((p - 1) // 2)
+
+
+def psi(P):
+    if P is None:
+        return None
+    x, y = P
+    return (x.frob() * PSI_X, y.frob() * PSI_Y)
+
+
+def clear_cofactor_g2(P):
+    xP = mul(BN_X, P)
+    return add(add(xP, psi(mul(3, xP))), add(psi(psi(xP)), psi(psi(psi(P)))))
+
+
+G1_MAP = SvdW(Fp, Fp(0), Fp(3))
+G2_B = Fp2(3) * XI.inv0()
+G2_MAP = SvdW(Fp2, Fp2(0), G2_B)
+
+
+def hash_to_g1(msg, dst, count):
+    us = [Fp(e[0]) for e in hash_to_field(msg, dst, count, 1)]
+    q = None
+    for u in us:
+        q = add(q, G1_MAP.map(u))
+    return q
+
+
+def hash_to_g2(msg, dst, count):
+    us = [Fp2(e[0], e[1]) for e in hash_to_field(msg, dst, count, 2)]
+    q = None
+    for u in us:
+        q = add(q, G2_MAP.map(u))
+    return clear_cofactor_g2(q)
+
+
+MESSAGES = [b"", b"abc", b"abcdef0123456789", b"q128_" + b"q" * 128, b"a512_" + b"a" * 512]
+
+# gnark-crypto ecc/bn254/hash_vectors_test.go, also used by src/bn256/curve.rs
+G1_NU = [
+    (0x1BB8810E2CEAF04786D4EFD216FC2820DDD9363712EFC736ADA11049D8AF5925,
+     0x1EFBF8D54C60D865CCE08437668EA30F5BF90D287DBD9B5AF31DA852915E8F11),
+    (0x0DA4A96147DF1F35B0F820BD35C6FAC3B80E8E320DE7C536B1E054667B22C332,
+     0x189BD3FBFFE4C8740D6543754D95C790E44CD2D162858E3B733D2B8387983BB7),
+    (0x2FF727CFAAADB3ACAB713FA22D91F5FDDAB3ED77948F3EF6233D7EA9B03F4DA1,
+     0x304080768FD2F87A852155B727F97DB84B191E41970506F0326ED4046D1141AA),
+    (0x11A2EAA8E3E89DE056D1B3A288A7F733C8A1282EFA41D28E71AF065AB245DF9B,
+     0x060F37C447AC29FD97B9BB83BE98DDCCF15E34831A9CDF5493B7FEDE0777AE06),
+    (0x27409DCCC6EE4CE90E24744FDA8D72C0BC64E79766F778DA0C1C0EF1C186EA84,
+     0x1AC201A542FECA15E77F30370DA183514DC99D8A0B2C136D64EDE35CD0B51DC0),
+]
+
+
+def main():
+    assert G1_MAP.Z == Fp(1)
+    dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_"
+    for msg, (x, y) in zip(MESSAGES, G1_NU):
+        P = hash_to_g1(msg, dst, 1)
+        assert (P[0].v, P[1].v) == (x, y), msg
+
+    print("Z =", (G2_MAP.Z.c0, G2_MAP.Z.c1))
+    for suffix, count in [("RO", 2), ("NU", 1)]:
+        dst = ("QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_%s_" % suffix).encode()
+        print(dst.decode())
+        for msg in MESSAGES:
+            P = hash_to_g2(msg, dst, count)
+            assert mul(r, P) is None
+            x, y = P
+            print(msg[:16], ["0x%064x" % c for c in (x.c0, x.c1, y.c0, y.c1)])
+
+
+if __name__ == "__main__":
+    main()
@@ -28,9 +28,13 @@ endo!(G1Projective, Scalar, ENDO_PARAMS_BLS);
 
 #[test]
 fn test_endo() {
+    use crate::CurveExt;
     use ff::Field;
     use rand_core::OsRng;
 
+    let g = G1Projective::generator();
+    assert_eq!(g * Scalar::ZETA, g.endo());
+
     for _ in 0..100000 {
         let k = Scalar::random(OsRng);
         let (k1, k1_neg, k2, k2_neg) = G1Projective::decompose_scalar(&k);
@@ -786,7 +786,14 @@ impl CurveExt for G1Projective {
     const CURVE_ID: &'static str = "Bls12-381";
 
     fn endo(&self) -> Self {
-        endomorphism(&G1Affine::from(self)).into()
+        // `BETA` is the cube root of unity matching `ZETA^2` on the scalar side, so we
+        // multiply by `BETA^2 = -1 - BETA` instead to get `endo(P) = [ZETA] P`, which
+        // is what `CurveEndo::decompose_scalar` is defined against.
+        G1Projective {
+            x: -(self.x + self.x * BETA),
+            y: self.y,
+            z: self.z,
+        }
     }
 
     fn jacobian_coordinates(&self) -> (Fp, Fp, Fp) {
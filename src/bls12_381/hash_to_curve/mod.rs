@@ -9,8 +9,7 @@ use subtle::Choice;
 
 pub(crate) mod chain;
 
-mod expand_msg;
-pub use self::expand_msg::{
+pub use crate::hash_to_curve::{
     ExpandMessage, ExpandMessageState, ExpandMsgXmd, ExpandMsgXof, InitExpandMessage,
 };
 
@@ -6,6 +6,7 @@ use super::fp2::Fp2;
 use super::fp6::Fp6;
 use super::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar, BLS_X, BLS_X_IS_NEGATIVE};
 
+use crate::msm::{multiexp_cyclotomic, CyclotomicGroup};
 use core::borrow::Borrow;
 use core::fmt;
 use core::iter::Sum;
@@ -48,71 +49,6 @@ impl MillerLoopResult {
     /// operation in the so-called `cyclotomic subgroup` of `Fq6` so that
     /// it can be compared with other elements of `Gt`.
     pub fn final_exponentiation(&self) -> Gt {
-        #[must_use]
-        fn fp4_square(a: Fp2, b: Fp2) -> (Fp2, Fp2) {
-            let t0 = a.square();
-            let t1 = b.square();
-            let mut t2 = t1.mul_by_nonresidue();
-            let c0 = t2 + t0;
-            t2 = a + b;
-            t2 = t2.square();
-            t2 -= t0;
-            let c1 = t2 - t1;
-
-            (c0, c1)
-        }
-        // Adaptation of Algorithm 5.5.4, Guide to Pairing-Based Cryptography
-        // Faster Squaring in the Cyclotomic Subgroup of Sixth Degree Extensions
-        // https://eprint.iacr.org/2009/565.pdf
-        #[must_use]
-        fn cyclotomic_square(f: Fp12) -> Fp12 {
-            let mut z0 = f.c0.c0;
-            let mut z4 = f.c0.c1;
-            let mut z3 = f.c0.c2;
-            let mut z2 = f.c1.c0;
-            let mut z1 = f.c1.c1;
-            let mut z5 = f.c1.c2;
-
-            let (t0, t1) = fp4_square(z0, z1);
-
-            // For A
-            z0 = t0 - z0;
-            z0 = z0 + z0 + t0;
-
-            z1 = t1 + z1;
-            z1 = z1 + z1 + t1;
-
-            let (mut t0, t1) = fp4_square(z2, z3);
-            let (t2, t3) = fp4_square(z4, z5);
-
-            // For C
-            z4 = t0 - z4;
-            z4 = z4 + z4 + t0;
-
-            z5 = t1 + z5;
-            z5 = z5 + z5 + t1;
-
-            // For B
-            t0 = t3.mul_by_nonresidue();
-            z2 = t0 + z2;
-            z2 = z2 + z2 + t0;
-
-            z3 = t2 - z3;
-            z3 = z3 + z3 + t2;
-
-            Fp12 {
-                c0: Fp6 {
-                    c0: z0,
-                    c1: z4,
-                    c2: z3,
-                },
-                c1: Fp6 {
-                    c0: z2,
-                    c1: z1,
-                    c2: z5,
-                },
-            }
-        }
         #[must_use]
         fn cycolotomic_exp(f: Fp12) -> Fp12 {
             let x = BLS_X;
@@ -120,7 +56,7 @@ impl MillerLoopResult {
             let mut found_one = false;
             for i in (0..64).rev().map(|b| ((x >> b) & 1) == 1) {
                 if found_one {
-                    tmp = cyclotomic_square(tmp)
+                    tmp = tmp.cyclotomic_square()
                 } else {
                     found_one = i;
                 }
@@ -147,9 +83,9 @@ impl MillerLoopResult {
                 t1 = t2;
                 t2 = t2.frobenius_map().frobenius_map();
                 t2 *= t1;
-                t1 = cyclotomic_square(t2).conjugate();
+                t1 = t2.cyclotomic_square().conjugate();
                 let mut t3 = cycolotomic_exp(t2);
-                let mut t4 = cyclotomic_square(t3);
+                let mut t4 = t3.cyclotomic_square();
                 let mut t5 = t1 * t3;
                 t1 = cycolotomic_exp(t5);
                 t0 = cycolotomic_exp(t1);
@@ -257,6 +193,14 @@ impl Gt {
     pub fn double(&self) -> Gt {
         Gt(self.0.square())
     }
+
+    /// Computes $\sum_i k_i \cdot P_i$, i.e. the product $\prod_i P_i^{k_i}$ in
+    /// multiplicative notation, with a Pippenger multi-exponentiation.
+    ///
+    /// This function will panic if coeffs and bases have a different length.
+    pub fn multiexp(coeffs: &[Scalar], bases: &[Gt]) -> Gt {
+        multiexp_cyclotomic(coeffs, bases)
+    }
 }
 
 impl<'a> Neg for &'a Gt {
@@ -486,6 +430,12 @@ impl Group for Gt {
     }
 }
 
+impl CyclotomicGroup for Gt {
+    fn cyclotomic_double(&self) -> Self {
+        Gt(self.0.cyclotomic_square())
+    }
+}
+
 #[derive(Clone, Debug)]
 /// This structure contains cached computations pertaining to a $\mathbb{G}_2$
 /// element as part of the pairing function (specifically, the Miller loop) and
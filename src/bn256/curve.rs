@@ -5,12 +5,16 @@ use crate::arithmetic::{CurveAffineExt, CurveEndo};
 use crate::bn256::Fq;
 use crate::bn256::Fq2;
 use crate::bn256::Fr;
+use crate::bn256::{BN_X, FROBENIUS_COEFF_FQ6_C1, XI_TO_Q_MINUS_1_OVER_2};
 use crate::endo;
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::Curve;
 use crate::group::{cofactor::CofactorGroup, prime::PrimeCurveAffine, Group, GroupEncoding};
-use crate::hash_to_curve::svdw_hash_to_curve;
+use crate::hash_to_curve::{
+    blake2b_hash_to_curve, hash_to_field, svdw_hash_to_curve, svdw_map_to_curve,
+    svdw_precomputed_constants, ExpandMessage, HashToCurveExt,
+};
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
     impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
@@ -53,7 +57,9 @@ new_curve_impl!(
     G2_A,
     G2_B,
     "bn256_g2",
-    |_, _| unimplemented!(),
+    |curve_id, domain_prefix| {
+        blake2b_hash_to_curve("SVDW", curve_id, domain_prefix, |[c0, c1]| Fq2 { c0, c1 })
+    },
 );
 
 const G1_GENERATOR_X: Fq = Fq::one();
@@ -198,10 +204,89 @@ impl G1 {
     const SVDW_Z: Fq = Fq::ONE;
 }
 
+impl HashToCurveExt for G1 {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fq]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    fn map_to_curve(u: &Fq) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fq; 4] = svdw_precomputed_constants::<G1>(G1::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, G1::SVDW_Z)
+    }
+}
+
+impl G2 {
+    /// Constant Z for the Shallue-van de Woestijne map, the output of `find_z_svdw` from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#svdw-z-code> over `Fq2`, see
+    /// `test_hash_to_curve_g2_regression`.
+    const SVDW_Z: Fq2 = Fq2::ONE;
+
+    /// The untwist-Frobenius-twist endomorphism
+    /// `psi(x, y) = (x^p * xi^((p - 1) / 3), y^p * xi^((p - 1) / 2))`.
+    fn psi(&self) -> Self {
+        let mut x = self.x;
+        x.conjugate();
+        let mut y = self.y;
+        y.conjugate();
+        let mut z = self.z;
+        z.conjugate();
+        G2 {
+            x: x * FROBENIUS_COEFF_FQ6_C1[1],
+            y: y * XI_TO_Q_MINUS_1_OVER_2,
+            z,
+        }
+    }
+
+    /// Multiplies by the BN parameter `BN_X`, which is public so there is no need for a
+    /// constant time ladder.
+    fn mul_by_x(&self) -> Self {
+        let mut acc = G2::identity();
+        for i in (0..64 - BN_X.leading_zeros()).rev() {
+            acc = acc.double();
+            if (BN_X >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+        acc
+    }
+}
+
+impl HashToCurveExt for G2 {
+    /// Hashes into `Fq2` with `m = 2`, so each element is built from two consecutive
+    /// `Fq` elements of the expanded message.
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fq2]) {
+        let mut coeffs = vec![Fq::ZERO; 2 * output.len()];
+        hash_to_field::<_, X>(message, dst, &mut coeffs);
+        for (out, c) in output.iter_mut().zip(coeffs.chunks(2)) {
+            *out = Fq2::new(c[0], c[1]);
+        }
+    }
+
+    fn map_to_curve(u: &Fq2) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fq2; 4] = svdw_precomputed_constants::<G2>(G2::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, G2::SVDW_Z)
+    }
+
+    /// Fast cofactor clearing from section 6.1 of
+    /// <https://cacr.uwaterloo.ca/techreports/2011/cacr2011-26.pdf>:
+    /// `[x]P + psi([3x]P) + psi^2([x]P) + psi^3(P)`.
+    fn clear_h(&self) -> Self {
+        let x_p = self.mul_by_x();
+        let three_x_p = x_p.double() + x_p;
+        x_p + three_x_p.psi() + x_p.psi().psi() + self.psi().psi().psi()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::arithmetic::CurveEndo;
-    use crate::bn256::{Fr, G1, G2};
+    use crate::bn256::{Fq2, Fr, G1, G2};
     use crate::CurveExt;
     use ff::Field;
     use ff::{PrimeField, WithSmallOrderMulGroup};
@@ -210,6 +295,138 @@ mod tests {
     #[test]
     fn test_hash_to_curve() {
         crate::tests::curve::hash_to_curve_test::<G1>();
+        crate::tests::curve::hash_to_curve_with_test::<G1>();
+        crate::tests::curve::hash_to_curve_test::<G2>();
+        crate::tests::curve::hash_to_curve_with_test::<G2>();
+    }
+
+    #[test]
+    fn test_hash_to_curve_g2() {
+        use crate::bn256::BN_X;
+        use crate::hash_to_curve::{ExpandMsgXmd, HashToCurveExt};
+        use group::cofactor::CofactorGroup;
+
+        // psi acts as multiplication by 6x^2 on G2.
+        let g = G2::generator();
+        let lambda = Fr::from_u128(6 * (BN_X as u128) * (BN_X as u128));
+        assert_eq!(g.psi(), g * lambda);
+
+        let dst = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+        for i in 0..100u32 {
+            let message = i.to_be_bytes();
+            let mut u = [Fq2::ZERO; 1];
+            G2::hash_to_field::<ExpandMsgXmd<sha2::Sha256>>(&message, dst, &mut u);
+
+            // Points from the map are on the twist but usually not in G2.
+            let p = G2::map_to_curve(&u[0]);
+            assert!(bool::from(p.is_on_curve()));
+            assert!(bool::from(p.clear_h().is_torsion_free()));
+            assert!(bool::from(p.clear_cofactor().is_torsion_free()));
+
+            let q = G2::hash_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(&message, dst);
+            assert!(bool::from(q.is_torsion_free()));
+            let q = G2::encode_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(&message, dst);
+            assert!(bool::from(q.is_torsion_free()));
+            let q = G2::hash_to_curve("test")(&message);
+            assert!(bool::from(q.is_torsion_free()));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve_g2_regression() {
+        use crate::hash_to_curve::{ExpandMsgXmd, HashToCurveExt};
+
+        // Both suites take the Z returned by `find_z_svdw`.
+        crate::tests::curve::find_z_svdw_test::<G1>(G1::SVDW_Z);
+        crate::tests::curve::find_z_svdw_test::<G2>(G2::SVDW_Z);
+
+        // Regression vectors generated with `script/bn254_hash_to_curve.py`. The script
+        // reproduces the gnark-crypto G1 vectors of `test_encode_to_curve_with`, but its
+        // G2 output has not been checked against an independent implementation, so these
+        // do not establish interoperability. Coordinates are written as `c0,c1`.
+        let dst = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+        crate::tests::curve::hash_to_curve_vector_test(
+            |message| G2::hash_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(message, dst),
+            [
+                (
+                    "".to_string(),
+                    (
+                        "0x1192005a0f121921a6d5629946199e4b27ff8ee4d6dd4f9581dc550ade851300,0x1747d950a6f23c16156e2171bce95d1189b04148ad12628869ed21c96a8c9335",
+                        "0x0498f6bb5ac309a07d9a8b88e6ff4b8de0d5f27a075830e1eb0e68ea318201d8,0x2c9755350ca363ef2cf541005437221c5740086c2e909b71d075152484e845f4",
+                    ),
+                ),
+                (
+                    "abc".to_string(),
+                    (
+                        "0x16c88b54eec9af86a41569608cd0f60aab43464e52ce7e6e298bf584b94fccd2,0x0b5db3ca7e8ef5edf3a33dfc3242357fbccead98099c3eb564b3d9d13cba4efd",
+                        "0x1c42ba524cb74db8e2c680449746c028f7bea923f245e69f89256af2d6c5f3ac,0x22d02d2da7f288545ff8789e789902245ab08c6b1d253561eec789ec2c1bd630",
+                    ),
+                ),
+                (
+                    "abcdef0123456789".to_string(),
+                    (
+                        "0x1435fd84aa43c699230e371f6fea3545ce7e053cbbb06a320296a2b81efddc70,0x2a8a360585b6b05996ef69c3c09b2c6fb17afe2b1e944f07559c53178eabf171",
+                        "0x2820188dcdc13ffdca31694942418afa1d6dfaaf259d012fab4da52b0f592e38,0x142f08e2441ec431defc24621b73cfe0252d19b243cb55b84bdeb85de039207a",
+                    ),
+                ),
+                (
+                    format!("q128_{}", "q".repeat(128)),
+                    (
+                        "0x2cffc213fb63d00d923cb22cda5a2904837bb93a2fe6e875c532c51744388341,0x2718ef38d1bc4347f0266c774c8ef4ee5fa7056cc27a4bd7ecf7a888efb95b26",
+                        "0x232553f728341afa64ce66d00535764557a052e38657594e10074ad28728c584,0x2206ec0a9288f31ed78531c37295df3b56c42a1284443ee9893adb1521779001",
+                    ),
+                ),
+                (
+                    format!("a512_{}", "a".repeat(512)),
+                    (
+                        "0x242a0a159f36f87065e7c5170426012087023165ce47a486e53d6e2845ca625a,0x17f9f6292998cf18ccc155903c1fe6b6465d40c794a3e1ed644a4182ad639f4a",
+                        "0x2dc5b7b65c9c79e6ef4afab8fbe3083c66d4ce31c78f6621ece17ecc892cf4b3,0x18ef4886c818f01fdf309bc9a46dd904273917f85e74ecd0de62460a68122037",
+                    ),
+                ),
+            ],
+        );
+
+        let dst = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_NU_";
+        crate::tests::curve::hash_to_curve_vector_test(
+            |message| G2::encode_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(message, dst),
+            [
+                (
+                    "".to_string(),
+                    (
+                        "0x04e9ea7f5807198397a99e234e91d4b9e6cadf0135ebedd97fd75cffed6e994d,0x070077acfda8443392fb30222ba96b63f4b734e678494bf4ed0e07074b440a7b",
+                        "0x2d3653bf41ec170ce2d48774d02393c8d5f60fee5690b4f8cbc8531e269227f9,0x0a7cf5d0d356f0c4d163570209e5f8f749bf91dc2a7d9ba58199a95ce02242b4",
+                    ),
+                ),
+                (
+                    "abc".to_string(),
+                    (
+                        "0x101e2f3d9fa22cb435ecb67d5284dc27c247856d6de4e420e1812e0bcea5afd8,0x29226a3ca7415a541599274bf9e805050c82d443fd953481b17236325be3b6b7",
+                        "0x290bf12841dd276211effe86af369c11a2cb364c443981d0faf347cfb7b68715,0x2e7c8a61fe36735852597ac564966560afe0ef8221918d5534e57f3096f7047d",
+                    ),
+                ),
+                (
+                    "abcdef0123456789".to_string(),
+                    (
+                        "0x0fcda542dd52f0e527bf828e63fe2a1f63a05c9a5c7a28865cfef247c6e1e8a6,0x2d0bb492bb59847c106af8285fae5be0b5f96b6dcad56b3a0c7ddc364ae55a3a",
+                        "0x172d50b483e9bb9aa230e7cb82fbd522af1b73c1643bbd022614533311071780,0x0afb68b6e28f44f49d6ab4c3014e73f7e07fd4d0b13a9519b798e9f1927a47b9",
+                    ),
+                ),
+                (
+                    format!("q128_{}", "q".repeat(128)),
+                    (
+                        "0x1d050758368c65df07014cab4752d8244ddf21691ab6418a3493bcc2a946b38d,0x2596aa6bcb29439a9cdc7cfe0b9d247a890a4295dc17d053c293c7e40c27387f",
+                        "0x2f84eec5eaa87952d0d81c93c3f470c1e1a00d0ba307d8fda78b76841aca8e82,0x27aef639d6eb4157c6f076e9fdae2f9eb15042dea92304fc54ebd5f69c5c3443",
+                    ),
+                ),
+                (
+                    format!("a512_{}", "a".repeat(512)),
+                    (
+                        "0x013729abbd4fbe2a13bc742960afa9053a4e6be06ea712b0d18153a9ec3854a7,0x261e8ebaff3438064599465bb52880e8e8a663b27cfb6d794d90ac60437819a9",
+                        "0x132285a30dc36cc14da2d145390a6328e574155ebaece32856fb890d1f7ba16e,0x06bd9197b3c0c1cc4d17695042dcbaf0168329a113d358c3b17885f71a394986",
+                    ),
+                ),
+            ],
+        );
     }
 
     #[test]
@@ -264,6 +481,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_to_curve_with() {
+        use crate::hash_to_curve::{ExpandMsgXmd, HashToCurveExt};
+
+        // Same vectors as `test_map_to_curve`, this time hashed from the original messages.
+        // Taken from https://github.com/ConsenSys/gnark-crypto/blob/441dc0ffe639294b8d09e394f24ba7575577229c/ecc/bn254/hash_vectors_test.go#L4-L28
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_";
+        crate::tests::curve::hash_to_curve_vector_test(
+            |message| G1::encode_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(message, dst),
+            [
+                (
+                    "".to_string(),
+                    (
+                        "0x1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925",
+                        "0x1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11",
+                    ),
+                ),
+                (
+                    "abc".to_string(),
+                    (
+                        "0xda4a96147df1f35b0f820bd35c6fac3b80e8e320de7c536b1e054667b22c332",
+                        "0x189bd3fbffe4c8740d6543754d95c790e44cd2d162858e3b733d2b8387983bb7",
+                    ),
+                ),
+                (
+                    "abcdef0123456789".to_string(),
+                    (
+                        "0x2ff727cfaaadb3acab713fa22d91f5fddab3ed77948f3ef6233d7ea9b03f4da1",
+                        "0x304080768fd2f87a852155b727f97db84b191e41970506f0326ed4046d1141aa",
+                    ),
+                ),
+                (
+                    format!("q128_{}", "q".repeat(128)),
+                    (
+                        "0x11a2eaa8e3e89de056d1b3a288a7f733c8a1282efa41d28e71af065ab245df9b",
+                        "0x60f37c447ac29fd97b9bb83be98ddccf15e34831a9cdf5493b7fede0777ae06",
+                    ),
+                ),
+                (
+                    format!("a512_{}", "a".repeat(512)),
+                    (
+                        "0x27409dccc6ee4ce90e24744fda8d72c0bc64e79766f778da0c1c0ef1c186ea84",
+                        "0x1ac201a542feca15e77f30370da183514dc99d8a0b2c136d64ede35cd0b51dc0",
+                    ),
+                ),
+            ],
+        );
+    }
+
     #[test]
     fn test_curve() {
         crate::tests::curve::curve_tests::<G1>();
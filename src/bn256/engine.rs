@@ -8,6 +8,7 @@ use crate::bn256::fr::*;
 use crate::ff::{Field, PrimeField};
 use crate::group::cofactor::CofactorCurveAffine;
 use crate::group::Group;
+use crate::msm::{multiexp_cyclotomic, CyclotomicGroup};
 use core::borrow::Borrow;
 use core::iter::Sum;
 use core::ops::{Add, Mul, MulAssign, Neg, Sub};
@@ -96,6 +97,14 @@ impl Gt {
     pub fn double(&self) -> Gt {
         Gt(self.0.square())
     }
+
+    /// Computes $\sum_i k_i \cdot P_i$, i.e. the product $\prod_i P_i^{k_i}$ in
+    /// multiplicative notation, with a Pippenger multi-exponentiation.
+    ///
+    /// This function will panic if coeffs and bases have a different length.
+    pub fn multiexp(coeffs: &[Fr], bases: &[Gt]) -> Gt {
+        multiexp_cyclotomic(coeffs, bases)
+    }
 }
 
 impl<'a> Neg for &'a Gt {
@@ -202,6 +211,14 @@ impl Group for Gt {
     }
 }
 
+impl CyclotomicGroup for Gt {
+    fn cyclotomic_double(&self) -> Self {
+        let mut u = self.0;
+        u.cyclotomic_square();
+        Gt(u)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct G2Prepared {
     pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
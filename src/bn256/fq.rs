@@ -163,6 +163,8 @@ impl Fq {
 
 extend_field_legendre!(Fq);
 
+impl crate::hash_to_curve::Sgn0 for Fq {}
+
 impl ff::Field for Fq {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
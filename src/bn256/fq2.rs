@@ -311,6 +311,14 @@ impl Legendre for Fq2 {
     }
 }
 
+impl crate::hash_to_curve::Sgn0 for Fq2 {
+    /// `sgn0(c0) || (c0 == 0 && sgn0(c1))`, unlike [`PrimeField::is_odd`] which only
+    /// looks at `c0`.
+    fn sgn0(&self) -> Choice {
+        self.c0.is_odd() | (self.c0.is_zero() & self.c1.is_odd())
+    }
+}
+
 impl Field for Fq2 {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
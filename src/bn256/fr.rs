@@ -168,6 +168,8 @@ field_common!(
 impl_sum_prod!(Fr);
 extend_field_legendre!(Fr);
 
+impl crate::hash_to_curve::Sgn0 for Fr {}
+
 #[cfg(not(feature = "bn256-table"))]
 impl_from_u64!(Fr, R2);
 #[cfg(feature = "bn256-table")]
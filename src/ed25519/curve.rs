@@ -1,5 +1,8 @@
 use crate::ed25519::Fq;
 use crate::ed25519::Fr;
+use crate::hash_to_curve::{
+    blake2b_hash_to_curve, hash_to_field, ExpandMessage, ExpandMsgXmd, HashToCurveExt,
+};
 use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
 use core::cmp;
 use core::fmt::Debug;
@@ -362,8 +365,8 @@ impl CurveExt for Ed25519 {
         unimplemented!();
     }
 
-    fn hash_to_curve<'a>(_: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
-        unimplemented!();
+    fn hash_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        blake2b_hash_to_curve("ELL2", Self::CURVE_ID, domain_prefix, |[u]: [Fq; 1]| u)
     }
 
     fn a() -> Self::Base {
@@ -905,6 +908,92 @@ impl CurveAffineExt for Ed25519Affine {
     }
 }
 
+// `J = 486662`, the `A` coefficient of curve25519 in Montgomery form.
+const CURVE25519_J: Fq = Fq::from_raw([486662, 0, 0, 0]);
+
+impl Ed25519 {
+    /// Hashes `message` to the prime order subgroup with the
+    /// `edwards25519_XMD:SHA-512_ELL2_RO_` suite from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#section-8.5>.
+    pub fn hash_to_curve_ell2_ro(message: &[u8], dst: &[u8]) -> Self {
+        Self::hash_to_curve_with::<ExpandMsgXmd<sha2::Sha512>>(message, dst)
+    }
+
+    /// Encodes `message` to the prime order subgroup with the
+    /// `edwards25519_XMD:SHA-512_ELL2_NU_` suite from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#section-8.5>.
+    pub fn encode_to_curve_ell2_nu(message: &[u8], dst: &[u8]) -> Self {
+        Self::encode_to_curve_with::<ExpandMsgXmd<sha2::Sha512>>(message, dst)
+    }
+}
+
+/// Implementation of <https://datatracker.ietf.org/doc/html/rfc9380#name-elligator-2-method>
+/// for curve25519 (`K = 1`, `Z = 2`), returning the point `(s, t)` in Montgomery form.
+fn elligator2_map_to_curve25519(u: &Fq) -> (Fq, Fq) {
+    let j = CURVE25519_J;
+    let z = Fq::from(2);
+    let g = |x: Fq| ((x + j) * x + Fq::ONE) * x;
+
+    // 1. x1 = -(J / K) * inv0(1 + Z * u^2)
+    let x1 = -j * (Fq::ONE + z * u.square()).invert().unwrap_or(Fq::ZERO);
+    // 2. If x1 == 0, set x1 = -(J / K)
+    let x1 = Fq::conditional_select(&x1, &-j, x1.is_zero());
+    // 3. gx1 = x1^3 + (J / K) * x1^2 + x1 / K^2
+    let gx1 = g(x1);
+    // 4. x2 = -x1 - (J / K)
+    let x2 = -x1 - j;
+    // 5. gx2 = x2^3 + (J / K) * x2^2 + x2 / K^2
+    let gx2 = g(x2);
+    // 6. If is_square(gx1), set x = x1, y = sqrt(gx1) with sgn0(y) == 1.
+    let y1 = gx1.sqrt();
+    let e1 = y1.is_some();
+    let y1 = y1.unwrap_or(Fq::ZERO);
+    let y1 = Fq::conditional_select(&-y1, &y1, y1.is_odd());
+    // 7. Else set x = x2, y = sqrt(gx2) with sgn0(y) == 0.
+    let y2 = gx2.sqrt().unwrap_or(Fq::ZERO);
+    let y2 = Fq::conditional_select(&y2, &-y2, y2.is_odd());
+    let x = Fq::conditional_select(&x2, &x1, e1);
+    let y = Fq::conditional_select(&y2, &y1, e1);
+    // 8. s = x * K, t = y * K
+    (x, y)
+}
+
+impl HashToCurveExt for Ed25519 {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fq]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    /// Elligator 2 into curve25519 followed by the rational map to edwards25519 from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#name-rational-maps-from-montgome>.
+    fn map_to_curve(u: &Fq) -> Self {
+        lazy_static::lazy_static! {
+            // sqrt(-486664) with sgn0 equal to 0.
+            static ref C1: Fq = {
+                let c1 = (-(CURVE25519_J + Fq::from(2))).sqrt().unwrap();
+                Fq::conditional_select(&c1, &-c1, c1.is_odd())
+            };
+        }
+        let (s, t) = elligator2_map_to_curve25519(u);
+
+        // v = sqrt(-486664) * s / t, w = (s - 1) / (s + 1), and the identity when
+        // t == 0 or s == -1.
+        let v_den = t;
+        let w_den = s + Fq::ONE;
+        let den_inv = (v_den * w_den).invert();
+        let is_exceptional = den_inv.is_none();
+        let den_inv = den_inv.unwrap_or(Fq::ZERO);
+        let x = *C1 * s * w_den * den_inv;
+        let y = (s - Fq::ONE) * v_den * den_inv;
+
+        let p = Ed25519Affine { x, y }.to_extended();
+        Ed25519::conditional_select(&p, &Ed25519::identity(), is_exceptional)
+    }
+
+    fn clear_h(&self) -> Self {
+        self.mul_by_cofactor()
+    }
+}
+
 pub trait TwistedEdwardsCurveExt: CurveExt {
     fn a() -> <Self as CurveExt>::Base;
     fn d() -> <Self as CurveExt>::Base;
@@ -989,6 +1078,108 @@ fn test_serialization() {
     crate::tests::curve::random_serialization_test::<Ed25519>();
 }
 
+#[test]
+fn test_hash_to_curve() {
+    crate::tests::curve::hash_to_curve_test::<Ed25519>();
+    crate::tests::curve::hash_to_curve_with_test::<Ed25519>();
+}
+
+#[test]
+fn test_hash_to_curve_ell2() {
+    // `edwards25519_XMD:SHA-512_ELL2_RO_` vectors from
+    // https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.5.1
+    let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+    crate::tests::curve::hash_to_curve_vector_test(
+        |message| Ed25519::hash_to_curve_ell2_ro(message, dst),
+        [
+            (
+                "".to_string(),
+                (
+                    "0x3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+                    "0x09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+                ),
+            ),
+            (
+                "abc".to_string(),
+                (
+                    "0x608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+                    "0x1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+                ),
+            ),
+            (
+                "abcdef0123456789".to_string(),
+                (
+                    "0x6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+                    "0x53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+                ),
+            ),
+            (
+                format!("q128_{}", "q".repeat(128)),
+                (
+                    "0x5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+                    "0x2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+                ),
+            ),
+            (
+                format!("a512_{}", "a".repeat(512)),
+                (
+                    "0x0efcfde5898a839b00997fbe40d2ebe950bc81181afbd5cd6b9618aa336c1e8c",
+                    "0x6dc2fc04f266c5c27f236a80b14f92ccd051ef1ff027f26a07f8c0f327d8f995",
+                ),
+            ),
+        ],
+    );
+
+    // `edwards25519_XMD:SHA-512_ELL2_NU_` vectors from
+    // https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.5.2
+    let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+    crate::tests::curve::hash_to_curve_vector_test(
+        |message| Ed25519::encode_to_curve_ell2_nu(message, dst),
+        [
+            (
+                "".to_string(),
+                (
+                    "0x1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+                    "0x222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+                ),
+            ),
+            (
+                "abc".to_string(),
+                (
+                    "0x5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+                    "0x67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+                ),
+            ),
+            (
+                "abcdef0123456789".to_string(),
+                (
+                    "0x1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+                    "0x2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+                ),
+            ),
+            (
+                format!("q128_{}", "q".repeat(128)),
+                (
+                    "0x35fbdc5143e8a97afd3096f2b843e07df72e15bfca2eaf6879bf97c5d3362f73",
+                    "0x2af6ff6ef5ebba128b0774f4296cb4c2279a074658b083b8dcca91f57a603450",
+                ),
+            ),
+            (
+                format!("a512_{}", "a".repeat(512)),
+                (
+                    "0x6e5e1f37e99345887fc12111575fc1c3e36df4b289b8759d23af14d774b66bff",
+                    "0x2c90c3d39eb18ff291d33441b35f3262cdd307162cc97c31bfcc7a4245891a37",
+                ),
+            ),
+        ],
+    );
+
+    for i in 0..100u32 {
+        let p = Ed25519::hash_to_curve_ell2_ro(&i.to_be_bytes(), dst);
+        assert!(bool::from(p.is_torsion_free()));
+    }
+}
+
 // #[test]
 // #[allow(non_snake_case)]
 // fn eddsa_example() {
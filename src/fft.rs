@@ -1,6 +1,14 @@
+use crate::serde::SerdeObject;
 pub use crate::{CurveAffine, CurveExt};
-use ff::Field;
-use group::{GroupOpsOwned, ScalarMulOwned};
+use ff::{Field, PrimeField, WithSmallOrderMulGroup};
+use group::{Curve, GroupOpsOwned, ScalarMulOwned};
+use num_bigint::BigUint;
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelIterator,
+};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 
 /// This represents an element of a group with basic operations that can be
 /// performed. This allows an FFT implementation (for example) to operate
@@ -17,6 +25,13 @@ where
 {
 }
 
+/// Smallest `log_n` for which [`best_fft`] uses the radix-$4$ kernel.
+const RADIX4_MIN_LOG_N: u32 = 4;
+
+/// Smallest transform size for which the radix-$4$ butterflies of a layer are
+/// split across tasks, the sub-transforms are always computed in parallel.
+const RADIX4_PARALLEL_MIN_N: usize = 1 << 12;
+
 /// Performs a radix-$2$ Fast-Fourier Transformation (FFT) on a vector of size
 /// $n = 2^k$, when provided `log_n` = $k$ and an element of multiplicative
 /// order $n$ called `omega` ($\omega$). The result is that the vector `a`, when
@@ -26,37 +41,90 @@ where
 /// $\omega^{-1}$ in place of $\omega$ and dividing each resulting field element
 /// by $n$.
 ///
+/// Domains of at least $2^4$ elements use the radix-$4$ kernel
+/// [`radix4_butterfly_arithmetic`], which yields the same output as the
+/// radix-$2$ one with half as many passes over the data.
+///
 /// This will use multithreading if beneficial.
 pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar, log_n: u32) {
-    fn bitreverse(mut n: usize, l: usize) -> usize {
-        let mut r = 0;
-        for _ in 0..l {
-            r = (r << 1) | (n & 1);
-            n >>= 1;
-        }
-        r
-    }
+    assert_eq!(a.len(), 1 << log_n);
+    fft_with_twiddles(a, &twiddles(omega, a.len() / 2), log_n)
+}
 
-    let threads = rayon::current_num_threads();
-    let log_threads = threads.ilog2();
-    let n = a.len();
-    assert_eq!(n, 1 << log_n);
+/// Performs [`best_fft`] on every column of `columns`, which must all have
+/// $n = 2^k$ elements.
+///
+/// The twiddle factors are computed once for the whole batch, and the work is
+/// spread across both the columns and the butterflies of each column.
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft_batch<Scalar: Field, G: FftGroup<Scalar>, C: AsMut<[G]> + Send>(
+    columns: &mut [C],
+    omega: Scalar,
+    log_n: u32,
+) {
+    fft_batch_with_twiddles(columns, &twiddles(omega, (1 << log_n) / 2), log_n)
+}
 
-    for k in 0..n {
-        let rk = bitreverse(k, log_n as usize);
-        if k < rk {
-            a.swap(rk, k);
-        }
-    }
+/// Same as [`best_fft_batch`], with the $n / 2$ twiddle factors $\omega^i$
+/// precomputed.
+fn fft_batch_with_twiddles<Scalar: Field, G: FftGroup<Scalar>, C: AsMut<[G]> + Send>(
+    columns: &mut [C],
+    twiddles: &[Scalar],
+    log_n: u32,
+) {
+    columns.par_iter_mut().for_each(|column| {
+        let column = column.as_mut();
+        assert_eq!(column.len(), 1 << log_n);
+        fft_with_twiddles(column, twiddles, log_n)
+    });
+}
 
-    // precompute twiddle factors
-    let twiddles: Vec<_> = (0..(n / 2))
+/// Returns the first `len` powers of `omega`.
+fn twiddles<Scalar: Field>(omega: Scalar, len: usize) -> Vec<Scalar> {
+    (0..len)
         .scan(Scalar::ONE, |w, _| {
             let tw = *w;
             *w *= &omega;
             Some(tw)
         })
-        .collect();
+        .collect()
+}
+
+/// Same as [`best_fft`], with the $n / 2$ twiddle factors $\omega^i$ precomputed.
+fn fft_with_twiddles<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    twiddles: &[Scalar],
+    log_n: u32,
+) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+
+    bit_reverse_permutation(a);
+
+    dit_butterflies(a, twiddles, log_n)
+}
+
+/// Reverses the `l` least significant bits of `n`.
+fn bitreverse(mut n: usize, l: usize) -> usize {
+    let mut r = 0;
+    for _ in 0..l {
+        r = (r << 1) | (n & 1);
+        n >>= 1;
+    }
+    r
+}
+
+/// Performs the butterflies of a decimation-in-time FFT over $n = 2^k$ elements
+/// in bit-reversed order, leaving the result in natural order.
+fn dit_butterflies<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    twiddles: &[Scalar],
+    log_n: u32,
+) {
+    let threads = rayon::current_num_threads();
+    let log_threads = threads.ilog2();
+    let n = a.len();
 
     if log_n <= log_threads {
         let mut chunk = 2_usize;
@@ -87,8 +155,10 @@ pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar,
             chunk *= 2;
             twiddle_chunk /= 2;
         }
+    } else if log_n >= RADIX4_MIN_LOG_N {
+        radix4_butterfly_arithmetic(a, n, 1, twiddles)
     } else {
-        recursive_butterfly_arithmetic(a, n, 1, &twiddles)
+        recursive_butterfly_arithmetic(a, n, 1, twiddles)
     }
 }
 
@@ -131,3 +201,1132 @@ pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
             });
     }
 }
+
+/// This performs recursive radix-$4$ butterfly arithmetic, for $n$ elements
+/// in bit-reversed order.
+///
+/// Two radix-$2$ layers are merged into one pass over the data, and the
+/// sub-transforms are computed depth first so that they stay in cache. When
+/// $\log_2 n$ is odd the last layer of recursion is a radix-$2$ butterfly.
+pub fn radix4_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    n: usize,
+    twiddle_chunk: usize,
+    twiddles: &[Scalar],
+) {
+    match n {
+        1 => {}
+        2 => {
+            let t = a[1];
+            a[1] = a[0];
+            a[0] += &t;
+            a[1] -= &t;
+        }
+        _ => {
+            let m = n / 4;
+            let (left, right) = a.split_at_mut(n / 2);
+            let (a0, a1) = left.split_at_mut(m);
+            let (a2, a3) = right.split_at_mut(m);
+
+            let quarter =
+                |a: &mut [G]| radix4_butterfly_arithmetic(a, m, twiddle_chunk * 4, twiddles);
+            rayon::join(
+                || rayon::join(|| quarter(a0), || quarter(a1)),
+                || rayon::join(|| quarter(a2), || quarter(a3)),
+            );
+
+            // In bit-reversed order the quarters hold the sub-transforms of the
+            // inputs whose index is 0, 2, 1 and 3 modulo 4 respectively.
+            let half = twiddles.len();
+            let i = twiddles[m * twiddle_chunk];
+            let butterfly = |j: usize, x0: &mut G, x1: &mut G, x2: &mut G, x3: &mut G| {
+                let (mut t1, mut t2, mut t3) = (*x2, *x1, *x3);
+                // case when twiddle factors are one
+                if j != 0 {
+                    let w3_idx = 3 * j * twiddle_chunk;
+                    let w3 = if w3_idx < half {
+                        twiddles[w3_idx]
+                    } else {
+                        -twiddles[w3_idx - half]
+                    };
+                    t1 *= &twiddles[j * twiddle_chunk];
+                    t2 *= &twiddles[2 * j * twiddle_chunk];
+                    t3 *= &w3;
+                }
+
+                let mut u1 = *x0;
+                u1 -= &t2;
+                *x0 += &t2;
+                let mut v1 = t1;
+                v1 -= &t3;
+                v1 *= &i;
+                t1 += &t3;
+
+                *x2 = *x0;
+                *x0 += &t1;
+                *x2 -= &t1;
+                *x1 = u1;
+                *x1 += &v1;
+                *x3 = u1;
+                *x3 -= &v1;
+            };
+
+            if n >= RADIX4_PARALLEL_MIN_N {
+                let chunk = RADIX4_PARALLEL_MIN_N / 4;
+                a0.par_chunks_mut(chunk)
+                    .zip(a1.par_chunks_mut(chunk))
+                    .zip(a2.par_chunks_mut(chunk))
+                    .zip(a3.par_chunks_mut(chunk))
+                    .enumerate()
+                    .for_each(|(c, (((a0, a1), a2), a3))| {
+                        for (j, (((x0, x1), x2), x3)) in a0
+                            .iter_mut()
+                            .zip(a1.iter_mut())
+                            .zip(a2.iter_mut())
+                            .zip(a3.iter_mut())
+                            .enumerate()
+                        {
+                            butterfly(c * chunk + j, x0, x1, x2, x3);
+                        }
+                    });
+            } else {
+                for (j, (((x0, x1), x2), x3)) in a0
+                    .iter_mut()
+                    .zip(a1.iter_mut())
+                    .zip(a2.iter_mut())
+                    .zip(a3.iter_mut())
+                    .enumerate()
+                {
+                    butterfly(j, x0, x1, x2, x3);
+                }
+            }
+        }
+    }
+}
+
+/// Multiplies the $i$-th element of `a` by $g^i$.
+fn distribute_powers<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], g: Scalar) {
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((a.len() + num_threads - 1) / num_threads).max(1);
+    a.par_chunks_mut(chunk).enumerate().for_each(|(i, a)| {
+        let mut w = g.pow_vartime([(i * chunk) as u64]);
+        for a in a.iter_mut() {
+            *a *= &w;
+            w *= &g;
+        }
+    });
+}
+
+/// Returns the primitive $2^k$-th root of unity derived from `F::ROOT_OF_UNITY`.
+fn root_of_unity<F: PrimeField>(k: u32) -> F {
+    let mut omega = F::ROOT_OF_UNITY;
+    for _ in k..F::S {
+        omega = omega.square();
+    }
+    omega
+}
+
+/// A multiplicative subgroup of size $n = 2^k$ of a prime field, together with
+/// the coset $g \cdot \langle \omega \rangle$ for the multiplicative generator $g$.
+///
+/// Twiddle factors and $n^{-1}$ are computed once, so that repeated transforms
+/// over the same domain don't recompute them.
+#[derive(Clone, Debug)]
+pub struct EvaluationDomain<F: PrimeField> {
+    k: u32,
+    omega: F,
+    omega_inv: F,
+    n_inv: F,
+    coset_shift: F,
+    coset_shift_inv: F,
+    twiddles: Vec<F>,
+    inv_twiddles: Vec<F>,
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Creates the domain of size $2^k$.
+    ///
+    /// Panics if `k` exceeds the 2-adicity `F::S` of the field.
+    pub fn new(k: u32) -> Self {
+        assert!(k <= F::S, "field has no subgroup of size 2^{k}");
+
+        let omega = root_of_unity::<F>(k);
+        let omega_inv = omega.invert().unwrap();
+        let n_inv = F::from(1 << k).invert().unwrap();
+        let coset_shift = F::MULTIPLICATIVE_GENERATOR;
+        let coset_shift_inv = coset_shift.invert().unwrap();
+
+        let half = (1 << k) / 2;
+        EvaluationDomain {
+            k,
+            omega,
+            omega_inv,
+            n_inv,
+            coset_shift,
+            coset_shift_inv,
+            twiddles: twiddles(omega, half),
+            inv_twiddles: twiddles(omega_inv, half),
+        }
+    }
+
+    /// Returns $k$, the base-$2$ logarithm of the domain size.
+    pub fn k(&self) -> u32 {
+        self.k
+    }
+
+    /// Returns the domain size $n = 2^k$.
+    pub fn size(&self) -> usize {
+        1 << self.k
+    }
+
+    /// Returns $\omega$, the generator of the domain.
+    pub fn omega(&self) -> F {
+        self.omega
+    }
+
+    /// Returns $\omega^{-1}$.
+    pub fn omega_inv(&self) -> F {
+        self.omega_inv
+    }
+
+    /// Returns $n^{-1}$.
+    pub fn n_inv(&self) -> F {
+        self.n_inv
+    }
+
+    /// Returns the coset shift $g$.
+    pub fn coset_shift(&self) -> F {
+        self.coset_shift
+    }
+
+    /// Transforms the coefficients of a polynomial of degree $< n$ into its
+    /// evaluations over the domain.
+    ///
+    /// Panics if `a` does not have exactly $n$ elements.
+    pub fn fft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        assert_eq!(a.len(), self.size());
+        fft_with_twiddles(a, &self.twiddles, self.k);
+    }
+
+    /// Transforms the evaluations of a polynomial over the domain back into its
+    /// coefficients.
+    ///
+    /// Panics if `a` does not have exactly $n$ elements.
+    pub fn ifft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        assert_eq!(a.len(), self.size());
+        fft_with_twiddles(a, &self.inv_twiddles, self.k);
+        let n_inv = self.n_inv;
+        a.par_iter_mut().for_each(|a| *a *= &n_inv);
+    }
+
+    /// Performs [`Self::fft`] on every column of `columns`, sharing the twiddle
+    /// factors of the domain.
+    ///
+    /// Panics if a column does not have exactly $n$ elements.
+    pub fn fft_batch<G: FftGroup<F>, C: AsMut<[G]> + Send>(&self, columns: &mut [C]) {
+        fft_batch_with_twiddles(columns, &self.twiddles, self.k);
+    }
+
+    /// Performs [`Self::ifft`] on every column of `columns`, sharing the twiddle
+    /// factors of the domain.
+    ///
+    /// Panics if a column does not have exactly $n$ elements.
+    pub fn ifft_batch<G: FftGroup<F>, C: AsMut<[G]> + Send>(&self, columns: &mut [C]) {
+        fft_batch_with_twiddles(columns, &self.inv_twiddles, self.k);
+        let n_inv = self.n_inv;
+        columns.par_iter_mut().for_each(|column| {
+            column.as_mut().par_iter_mut().for_each(|a| *a *= &n_inv);
+        });
+    }
+
+    /// Transforms the coefficients of a polynomial of degree $< n$ into its
+    /// evaluations over the coset $g \cdot \langle \omega \rangle$.
+    ///
+    /// Panics if `a` does not have exactly $n$ elements.
+    pub fn coset_fft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        distribute_powers(a, self.coset_shift);
+        self.fft(a);
+    }
+
+    /// Transforms the evaluations of a polynomial over the coset
+    /// $g \cdot \langle \omega \rangle$ back into its coefficients.
+    ///
+    /// Panics if `a` does not have exactly $n$ elements.
+    pub fn coset_ifft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        self.ifft(a);
+        distribute_powers(a, self.coset_shift_inv);
+    }
+
+    /// Evaluates the vanishing polynomial $Z(X) = X^n - 1$ of the domain at `x`.
+    pub fn evaluate_vanishing_polynomial(&self, x: F) -> F {
+        let mut x_n = x;
+        for _ in 0..self.k {
+            x_n = x_n.square();
+        }
+        x_n - F::ONE
+    }
+
+    /// Returns the value $g^n - 1$ taken by the vanishing polynomial at every
+    /// point of the coset $g \cdot \langle \omega \rangle$.
+    pub fn coset_vanishing_polynomial(&self) -> F {
+        self.evaluate_vanishing_polynomial(self.coset_shift)
+    }
+}
+
+/// Returns a primitive root of unity $\omega$ of order $2^{k} \cdot 3^{m}$, with
+/// `log2_n` = $k$ and `log3_n` = $m$, or `None` if the field has no such root.
+///
+/// The $3$-part of $\omega$ is chosen so that its $3^{m-1}$-th power is `ZETA`.
+pub fn mixed_radix_root_of_unity<F: WithSmallOrderMulGroup<3>>(
+    log2_n: u32,
+    log3_n: u32,
+) -> Option<F> {
+    if log2_n > F::S {
+        return None;
+    }
+    let omega = root_of_unity::<F>(log2_n);
+    if log3_n == 0 {
+        return Some(omega);
+    }
+
+    // (p - 1) / 3^m, from the big-endian hex modulus as the byte order of
+    // `to_repr` differs between fields
+    let modulus = BigUint::parse_bytes(F::MODULUS.trim_start_matches("0x").as_bytes(), 16)
+        .expect("modulus is a hex string");
+    let p_minus_one = modulus - 1u32;
+    let divisor = BigUint::from(3u32).pow(log3_n);
+    if &p_minus_one % &divisor != BigUint::default() {
+        return None;
+    }
+    let exp = (p_minus_one / divisor).to_u64_digits();
+
+    let mut omega3 = F::MULTIPLICATIVE_GENERATOR.pow_vartime(exp);
+    let mut zeta = omega3;
+    for _ in 1..log3_n {
+        zeta = zeta.square() * zeta;
+    }
+    if zeta == F::ZETA.square() {
+        omega3 = omega3.square();
+    } else if zeta != F::ZETA {
+        return None;
+    }
+    Some(omega * omega3)
+}
+
+/// Performs a mixed-radix Fast-Fourier Transformation (FFT) on a vector of size
+/// $n = 2^{k} \cdot 3^{m}$, when provided `log2_n` = $k$, `log3_n` = $m$ and an
+/// element of multiplicative order $n$ called `omega` ($\omega$), such as the one
+/// returned by [`mixed_radix_root_of_unity`].
+///
+/// As with [`best_fft`], the vector `a` of coefficients is transformed into the
+/// evaluations at the powers of $\omega$, and the transformation is inverted by
+/// providing $\omega^{-1}$ in place of $\omega$ and dividing by $n$. Power of two
+/// sizes are delegated to [`best_fft`].
+///
+/// This will use multithreading if beneficial.
+pub fn mixed_radix_fft<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    omega: Scalar,
+    log2_n: u32,
+    log3_n: u32,
+) {
+    let n = a.len();
+    assert_eq!(n, (1 << log2_n) * 3usize.pow(log3_n));
+    if log3_n == 0 {
+        return best_fft(a, omega, log2_n);
+    }
+
+    // radices from the innermost stage outwards
+    let radices: Vec<usize> = std::iter::repeat(3)
+        .take(log3_n as usize)
+        .chain(std::iter::repeat(2).take(log2_n as usize))
+        .collect();
+
+    // Mixed-radix digit reversal: the `t`-th sub-transform of a stage of radix
+    // `r` is taken over the inputs whose index is `t` modulo `r`.
+    let mut perm = vec![0];
+    for &r in radices.iter() {
+        perm = (0..r)
+            .flat_map(|t| perm.iter().map(move |p| t + r * p))
+            .collect();
+    }
+    let permuted: Vec<G> = perm.par_iter().map(|&i| a[i]).collect();
+    a.copy_from_slice(&permuted);
+
+    let twiddles = twiddles(omega, n);
+    // primitive cube root of unity of the transform
+    let zeta = twiddles[n / 3];
+
+    let mut m = 1;
+    for &r in radices.iter() {
+        let stride = n / (r * m);
+        let chunk = mixed_radix_chunk(m);
+        let twiddles = &twiddles;
+        a.par_chunks_mut(r * m).for_each(|block| {
+            if r == 2 {
+                let (y0, y1) = block.split_at_mut(m);
+                y0.par_chunks_mut(chunk)
+                    .zip(y1.par_chunks_mut(chunk))
+                    .enumerate()
+                    .for_each(|(c, (y0, y1))| {
+                        for (k, (y0, y1)) in y0.iter_mut().zip(y1.iter_mut()).enumerate() {
+                            let k = c * chunk + k;
+                            let mut t = *y1;
+                            t *= &twiddles[stride * k];
+                            *y1 = *y0;
+                            *y0 += &t;
+                            *y1 -= &t;
+                        }
+                    });
+            } else {
+                let (y0, rest) = block.split_at_mut(m);
+                let (y1, y2) = rest.split_at_mut(m);
+                y0.par_chunks_mut(chunk)
+                    .zip(y1.par_chunks_mut(chunk))
+                    .zip(y2.par_chunks_mut(chunk))
+                    .enumerate()
+                    .for_each(|(c, ((y0, y1), y2))| {
+                        for (k, ((y0, y1), y2)) in y0
+                            .iter_mut()
+                            .zip(y1.iter_mut())
+                            .zip(y2.iter_mut())
+                            .enumerate()
+                        {
+                            let k = c * chunk + k;
+                            let z0 = *y0;
+                            let mut z1 = *y1;
+                            z1 *= &twiddles[stride * k];
+                            let mut z2 = *y2;
+                            z2 *= &twiddles[2 * stride * k];
+
+                            // zeta^2 = -1 - zeta, so a single multiplication by
+                            // zeta is needed
+                            let mut d = z1;
+                            d -= &z2;
+                            d *= &zeta;
+
+                            *y0 += &z1;
+                            *y0 += &z2;
+                            *y1 = z0;
+                            *y1 -= &z2;
+                            *y1 += &d;
+                            *y2 = z0;
+                            *y2 -= &z1;
+                            *y2 -= &d;
+                        }
+                    });
+            }
+        });
+        m *= r;
+    }
+}
+
+/// Number of butterflies of a mixed-radix stage handled by one task, for
+/// sub-transforms of size `m`.
+fn mixed_radix_chunk(m: usize) -> usize {
+    let num_threads = rayon::current_num_threads();
+    ((m + num_threads - 1) / num_threads).max(1 << 10)
+}
+
+/// Converts the first $n$ points $[\tau^i] G$ of a monomial SRS into the
+/// Lagrange-basis SRS $[L_i(\tau)] G$ over `domain`, where $L_i$ is the
+/// Lagrange polynomial that is $1$ at $\omega^i$ and $0$ elsewhere on the domain.
+///
+/// Since $L_i(X) = n^{-1} \sum_j \omega^{-ij} X^j$, this is an inverse FFT over
+/// the group, followed by a batch normalization.
+///
+/// Panics if `monomial` has fewer than $n$ points.
+///
+/// This will use multithreading if beneficial.
+pub fn srs_monomial_to_lagrange<C: CurveAffine>(
+    monomial: &[C],
+    domain: &EvaluationDomain<C::Scalar>,
+) -> Vec<C> {
+    assert!(monomial.len() >= domain.size());
+    let mut points: Vec<C::Curve> = monomial[..domain.size()]
+        .par_iter()
+        .map(|p| p.to_curve())
+        .collect();
+    domain.ifft(&mut points);
+    batch_normalize(&points)
+}
+
+/// Converts a Lagrange-basis SRS $[L_i(\tau)] G$ over `domain` back into the
+/// monomial SRS $[\tau^i] G$, with a forward FFT over the group.
+///
+/// Panics if `lagrange` does not have exactly $n$ points.
+///
+/// This will use multithreading if beneficial.
+pub fn srs_lagrange_to_monomial<C: CurveAffine>(
+    lagrange: &[C],
+    domain: &EvaluationDomain<C::Scalar>,
+) -> Vec<C> {
+    let mut points: Vec<C::Curve> = lagrange.par_iter().map(|p| p.to_curve()).collect();
+    domain.fft(&mut points);
+    batch_normalize(&points)
+}
+
+/// Normalizes projective points to affine, with one batch inversion per thread.
+fn batch_normalize<C: CurveAffine>(points: &[C::Curve]) -> Vec<C> {
+    let mut affine = vec![C::identity(); points.len()];
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((points.len() + num_threads - 1) / num_threads).max(1);
+    points
+        .par_chunks(chunk)
+        .zip(affine.par_chunks_mut(chunk))
+        .for_each(|(p, q)| C::Curve::batch_normalize(p, q));
+    affine
+}
+
+/// This performs recursive butterfly arithmetic of a decimation-in-frequency
+/// FFT, which takes its input in natural order and leaves the result in
+/// bit-reversed order.
+fn dif_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    n: usize,
+    twiddle_chunk: usize,
+    twiddles: &[Scalar],
+) {
+    if n == 1 {
+        return;
+    }
+    let (left, right) = a.split_at_mut(n / 2);
+    left.iter_mut()
+        .zip(right.iter_mut())
+        .enumerate()
+        .for_each(|(i, (a, b))| {
+            let t = *b;
+            *b = *a;
+            *a += &t;
+            *b -= &t;
+            // case when twiddle factor is one
+            if i != 0 {
+                *b *= &twiddles[i * twiddle_chunk];
+            }
+        });
+    if n > 2 {
+        rayon::join(
+            || dif_butterfly_arithmetic(left, n / 2, twiddle_chunk * 2, twiddles),
+            || dif_butterfly_arithmetic(right, n / 2, twiddle_chunk * 2, twiddles),
+        );
+    }
+}
+
+/// Ordering of the elements of a vector given to or returned by an FFT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FftOrder {
+    /// The $i$-th element is at index $i$.
+    Natural,
+    /// The $i$-th element is at the index whose $k$ bits are those of $i$ in
+    /// reverse order.
+    BitReversed,
+}
+
+/// Performs a decimation-in-time FFT on a vector of size $n = 2^k$, taking its
+/// input in bit-reversed order and leaving the result in natural order.
+///
+/// This is [`best_fft`] without its up front bit-reversal permutation.
+///
+/// This will use multithreading if beneficial.
+pub fn dit_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar, log_n: u32) {
+    assert_eq!(a.len(), 1 << log_n);
+    dit_butterflies(a, &twiddles(omega, a.len() / 2), log_n)
+}
+
+/// Performs a decimation-in-frequency FFT on a vector of size $n = 2^k$,
+/// taking its input in natural order and leaving the result in bit-reversed
+/// order.
+///
+/// This will use multithreading if beneficial.
+pub fn dif_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar, log_n: u32) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+    dif_butterfly_arithmetic(a, n, 1, &twiddles(omega, n / 2))
+}
+
+/// Performs the same transformation as [`best_fft`], with explicit orderings
+/// of the input and of the output.
+///
+/// Mixed orderings need no permutation at all, using [`dit_fft`] or
+/// [`dif_fft`].
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft_ordered<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    omega: Scalar,
+    log_n: u32,
+    input: FftOrder,
+    output: FftOrder,
+) {
+    match (input, output) {
+        (FftOrder::Natural, FftOrder::Natural) => best_fft(a, omega, log_n),
+        (FftOrder::BitReversed, FftOrder::Natural) => dit_fft(a, omega, log_n),
+        (FftOrder::Natural, FftOrder::BitReversed) => dif_fft(a, omega, log_n),
+        (FftOrder::BitReversed, FftOrder::BitReversed) => {
+            assert_eq!(a.len(), 1 << log_n);
+            bit_reverse_permutation(a);
+            dif_fft(a, omega, log_n)
+        }
+    }
+}
+
+/// Base-$2$ logarithm of the side of the tiles moved by
+/// [`bit_reverse_permutation`].
+const BIT_REVERSE_TILE_BITS: u32 = 4;
+
+/// Permutes a vector of size $n = 2^k$ so that the element at index $i$ moves to
+/// the index whose $k$ bits are those of $i$ in reverse order.
+///
+/// Writing an index as high, middle and low bits $(a, m, b)$ with $a$ and $b$
+/// of the same width, the permutation maps $(a, m, b)$ to
+/// $(\bar b, \bar m, \bar a)$. The elements of a fixed middle $m$ form a small
+/// square tile that is gathered from contiguous rows and scattered to
+/// contiguous rows, which keeps memory accesses cache friendly. This uses
+/// $n$ elements of scratch space.
+///
+/// Panics if the length of `a` is not a power of two.
+///
+/// This will use multithreading if beneficial.
+pub fn bit_reverse_permutation<T: Copy + Send + Sync>(a: &mut [T]) {
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    let log_n = n.trailing_zeros();
+    if log_n <= 1 {
+        return;
+    }
+    let tile_bits = BIT_REVERSE_TILE_BITS.min(log_n / 2);
+    let middle_bits = log_n - 2 * tile_bits;
+    let side = 1 << tile_bits;
+
+    // tiles[m] holds the elements of middle bits m, as the rows of their
+    // destination indexed by the reversed low bits
+    let tiles: Vec<Vec<T>> = (0..1usize << middle_bits)
+        .into_par_iter()
+        .map(|m| {
+            let mut tile = Vec::with_capacity(side * side);
+            for rev_b in 0..side {
+                let b = bitreverse(rev_b, tile_bits as usize);
+                for rev_a in 0..side {
+                    let a_bits = bitreverse(rev_a, tile_bits as usize);
+                    tile.push(a[(a_bits << (log_n - tile_bits)) | (m << tile_bits) | b]);
+                }
+            }
+            tile
+        })
+        .collect();
+
+    // destination rows are indexed by (rev b, rev m)
+    a.par_chunks_mut(side).enumerate().for_each(|(row, dst)| {
+        let rev_b = row >> middle_bits;
+        let m = bitreverse(row & ((1 << middle_bits) - 1), middle_bits as usize);
+        dst.copy_from_slice(&tiles[m][rev_b * side..(rev_b + 1) * side]);
+    });
+}
+
+/// A column extended by [`lde`], given in natural order.
+#[derive(Clone, Copy, Debug)]
+pub enum LdeInput<'a, F> {
+    /// The $n$ coefficients of a polynomial of degree $< n$.
+    Coefficients(&'a [F]),
+    /// The evaluations of a polynomial of degree $< n$ over the subgroup of
+    /// size $n$.
+    Evaluations(&'a [F]),
+}
+
+/// Computes the low-degree extension of a column of size $n = 2^k$ by a factor
+/// `blowup`, i.e. the evaluations of its polynomial over the coset
+/// $s \cdot \langle \omega_{N} \rangle$ of the subgroup of size
+/// $N = n \cdot \text{blowup}$, where $s$ is `coset_shift`. The result is in
+/// natural order.
+///
+/// Evaluations are interpolated with a decimation-in-frequency inverse FFT,
+/// whose bit-reversed output is scaled by $n^{-1}$ and the powers of $s$, and
+/// spread with zero padding directly in the bit-reversed order of the
+/// decimation-in-time forward FFT. No bit-reversal pass is needed.
+///
+/// Panics if $n$ or `blowup` is not a power of two, or if $N$ exceeds
+/// $2^{S}$.
+///
+/// This will use multithreading if beneficial.
+pub fn lde<F: PrimeField>(input: LdeInput<F>, blowup: usize, coset_shift: F) -> Vec<F> {
+    let n = match input {
+        LdeInput::Coefficients(values) | LdeInput::Evaluations(values) => values.len(),
+    };
+    assert!(n.is_power_of_two() && blowup.is_power_of_two());
+    let log_n = n.trailing_zeros();
+    let log_extended = log_n + blowup.trailing_zeros();
+    assert!(log_extended <= F::S);
+
+    let shift_powers = twiddles(coset_shift, n);
+    // coefficients times the powers of the shift, in bit-reversed order
+    let coeffs: Vec<F> = match input {
+        LdeInput::Coefficients(coeffs) => (0..n)
+            .into_par_iter()
+            .map(|j| {
+                let i = bitreverse(j, log_n as usize);
+                coeffs[i] * shift_powers[i]
+            })
+            .collect(),
+        LdeInput::Evaluations(evals) => {
+            let mut coeffs = evals.to_vec();
+            let omega_inv = root_of_unity::<F>(log_n).invert().unwrap();
+            dif_butterfly_arithmetic(&mut coeffs, n, 1, &twiddles(omega_inv, n / 2));
+            let n_inv = F::from(n as u64).invert().unwrap();
+            coeffs.par_iter_mut().enumerate().for_each(|(j, c)| {
+                *c *= n_inv * shift_powers[bitreverse(j, log_n as usize)];
+            });
+            coeffs
+        }
+    };
+
+    // In bit-reversed order of size N, coefficient i < n lands at position
+    // bitreverse_n(i) * blowup.
+    let mut extended = vec![F::ZERO; n * blowup];
+    extended
+        .par_chunks_mut(blowup)
+        .zip(coeffs.par_iter())
+        .for_each(|(chunk, c)| chunk[0] = *c);
+    let omega = root_of_unity::<F>(log_extended);
+    dit_butterflies(
+        &mut extended,
+        &twiddles(omega, n * blowup / 2),
+        log_extended,
+    );
+    extended
+}
+
+/// Reads `len` consecutive elements starting at element `offset` of `storage`.
+fn read_elements<F: SerdeObject + Send, S: Read + Seek>(
+    storage: &mut S,
+    offset: usize,
+    len: usize,
+    element_size: usize,
+) -> io::Result<Vec<F>> {
+    let mut bytes = vec![0u8; len * element_size];
+    storage.seek(SeekFrom::Start((offset * element_size) as u64))?;
+    storage.read_exact(&mut bytes)?;
+    Ok(bytes
+        .par_chunks(element_size)
+        .map(F::from_raw_bytes_unchecked)
+        .collect())
+}
+
+/// Writes `elements` consecutively starting at element `offset` of `storage`.
+fn write_elements<F: SerdeObject + Sync, S: Write + Seek>(
+    storage: &mut S,
+    offset: usize,
+    elements: &[F],
+    element_size: usize,
+) -> io::Result<()> {
+    let bytes: Vec<u8> = elements
+        .par_iter()
+        .flat_map_iter(|element| element.to_raw_bytes())
+        .collect();
+    storage.seek(SeekFrom::Start((offset * element_size) as u64))?;
+    storage.write_all(&bytes)
+}
+
+/// Performs the same transformation as [`best_fft`] on $n = 2^k$ elements stored
+/// in `input`, writing the result in natural order to `output`, while keeping at
+/// most about `max_elements` elements in memory.
+///
+/// The storage can be a [`std::fs::File`], or a [`std::io::Cursor`] over a
+/// memory-mapped buffer. Elements are stored back to back with
+/// [`SerdeObject::write_raw`], and are
+/// decoded without checks as in [`SerdeObject::read_raw_unchecked`]. `input`
+/// is used as scratch space and is clobbered.
+///
+/// This is the four-step (Bailey) FFT: the vector is viewed as an
+/// $n_1 \times n_2$ row-major matrix, with $n_1 = 2^{\lfloor k/2 \rfloor}$ and
+/// $n_2 = 2^{\lceil k/2 \rceil}$. Blocks of columns are transformed, multiplied
+/// by the twiddle factors $\omega^{j_2 k_1}$ and written back in place, then
+/// blocks of rows are transformed and written transposed to `output`.
+///
+/// Panics if `max_elements` is smaller than $n_2$.
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft_on_disk<F, I, O>(
+    input: &mut I,
+    output: &mut O,
+    omega: F,
+    log_n: u32,
+    max_elements: usize,
+) -> io::Result<()>
+where
+    F: Field + SerdeObject,
+    I: Read + Write + Seek,
+    O: Write + Seek,
+{
+    let log_n1 = log_n / 2;
+    let log_n2 = log_n - log_n1;
+    let (n1, n2) = (1usize << log_n1, 1usize << log_n2);
+    assert!(
+        max_elements >= n2,
+        "memory bound too small for the row pass"
+    );
+    let element_size = F::ZERO.to_raw_bytes().len();
+
+    // column pass: transforms of size n1 with omega^n2, then twiddles
+    let omega_n1 = omega.pow_vartime([n2 as u64]);
+    let twiddles_n1 = twiddles(omega_n1, n1 / 2);
+    let block_columns = (max_elements / n1).clamp(1, n2);
+    for c0 in (0..n2).step_by(block_columns) {
+        let width = block_columns.min(n2 - c0);
+        let mut columns = vec![Vec::with_capacity(n1); width];
+        for j1 in 0..n1 {
+            let row: Vec<F> = read_elements(input, j1 * n2 + c0, width, element_size)?;
+            for (column, element) in columns.iter_mut().zip(row) {
+                column.push(element);
+            }
+        }
+        fft_batch_with_twiddles(&mut columns, &twiddles_n1, log_n1);
+        columns.par_iter_mut().enumerate().for_each(|(j, column)| {
+            let w = omega.pow_vartime([(c0 + j) as u64]);
+            let mut w_k1 = F::ONE;
+            for element in column.iter_mut() {
+                *element *= w_k1;
+                w_k1 *= w;
+            }
+        });
+        for j1 in 0..n1 {
+            let row: Vec<F> = columns.iter().map(|column| column[j1]).collect();
+            write_elements(input, j1 * n2 + c0, &row, element_size)?;
+        }
+    }
+
+    // row pass: transforms of size n2 with omega^n1, written transposed so that
+    // row k1 and column k2 lands at k1 + n1 * k2
+    let omega_n2 = omega.pow_vartime([n1 as u64]);
+    let twiddles_n2 = twiddles(omega_n2, n2 / 2);
+    let block_rows = (max_elements / n2).clamp(1, n1);
+    for r0 in (0..n1).step_by(block_rows) {
+        let height = block_rows.min(n1 - r0);
+        let block: Vec<F> = read_elements(input, r0 * n2, height * n2, element_size)?;
+        let mut rows: Vec<Vec<F>> = block.chunks(n2).map(|row| row.to_vec()).collect();
+        fft_batch_with_twiddles(&mut rows, &twiddles_n2, log_n2);
+        for k2 in 0..n2 {
+            let column: Vec<F> = rows.iter().map(|row| row[k2]).collect();
+            write_elements(output, k2 * n1 + r0, &column, element_size)?;
+        }
+    }
+    output.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{best_fft, EvaluationDomain};
+    use crate::bn256::{Fr, G1};
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+
+    fn eval(coeffs: &[Fr], x: Fr) -> Fr {
+        coeffs
+            .iter()
+            .rev()
+            .fold(Fr::ZERO, |acc, coeff| acc * x + coeff)
+    }
+
+    #[test]
+    fn test_evaluation_domain() {
+        for k in [0, 1, 2, 5, 10] {
+            let domain = EvaluationDomain::<Fr>::new(k);
+            let n = domain.size();
+            let coeffs: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+            let mut evals = coeffs.clone();
+            domain.fft(&mut evals);
+            let mut expected = coeffs.clone();
+            best_fft(&mut expected, domain.omega(), k);
+            assert_eq!(evals, expected);
+            let mut x = Fr::ONE;
+            for eval_at in evals.iter() {
+                assert_eq!(*eval_at, eval(&coeffs, x));
+                assert_eq!(domain.evaluate_vanishing_polynomial(x), Fr::ZERO);
+                x *= domain.omega();
+            }
+            domain.ifft(&mut evals);
+            assert_eq!(evals, coeffs);
+
+            let mut coset_evals = coeffs.clone();
+            domain.coset_fft(&mut coset_evals);
+            let mut x = domain.coset_shift();
+            for eval_at in coset_evals.iter() {
+                assert_eq!(*eval_at, eval(&coeffs, x));
+                assert_eq!(
+                    domain.evaluate_vanishing_polynomial(x),
+                    domain.coset_vanishing_polynomial()
+                );
+                x *= domain.omega();
+            }
+            domain.coset_ifft(&mut coset_evals);
+            assert_eq!(coset_evals, coeffs);
+        }
+    }
+
+    #[test]
+    fn test_radix4_butterfly_arithmetic() {
+        for log_n in 1..=13 {
+            let n = 1 << log_n;
+            let domain = EvaluationDomain::<Fr>::new(log_n);
+            let twiddles = super::twiddles(domain.omega(), n / 2);
+            let mut radix2: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let mut radix4 = radix2.clone();
+            super::recursive_butterfly_arithmetic(&mut radix2, n, 1, &twiddles);
+            super::radix4_butterfly_arithmetic(&mut radix4, n, 1, &twiddles);
+            assert_eq!(radix2, radix4);
+        }
+    }
+
+    #[test]
+    fn test_mixed_radix_fft() {
+        use ff::WithSmallOrderMulGroup;
+
+        // r - 1 is divisible by 3^2 but not 3^3 for bn256
+        assert!(super::mixed_radix_root_of_unity::<Fr>(0, 3).is_none());
+        assert!(super::mixed_radix_root_of_unity::<Fr>(29, 0).is_none());
+
+        for (log2_n, log3_n) in [(0, 1), (3, 1), (0, 2), (4, 2), (9, 1)] {
+            let n = (1 << log2_n) * 3usize.pow(log3_n);
+            let omega = super::mixed_radix_root_of_unity::<Fr>(log2_n, log3_n).unwrap();
+            assert_eq!(omega.pow_vartime([n as u64]), Fr::ONE);
+            assert_eq!(omega.pow_vartime([(n / 3) as u64]).cube(), Fr::ONE);
+            assert_ne!(omega.pow_vartime([(n / 3) as u64]), Fr::ONE);
+            if log2_n > 0 {
+                assert_ne!(omega.pow_vartime([(n / 2) as u64]), Fr::ONE);
+            }
+            if log2_n == 0 {
+                assert_eq!(omega.pow_vartime([(n / 3) as u64]), Fr::ZETA);
+            }
+
+            let coeffs: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let mut evals = coeffs.clone();
+            super::mixed_radix_fft(&mut evals, omega, log2_n, log3_n);
+            let mut x = Fr::ONE;
+            for eval_at in evals.iter() {
+                assert_eq!(*eval_at, eval(&coeffs, x));
+                x *= omega;
+            }
+
+            let omega_inv = omega.invert().unwrap();
+            let mut inverse = evals.clone();
+            super::mixed_radix_fft(&mut inverse, omega_inv, log2_n, log3_n);
+            let mut x = Fr::ONE;
+            for eval_at in inverse.iter() {
+                assert_eq!(*eval_at, eval(&evals, x));
+                x *= omega_inv;
+            }
+
+            evals = inverse;
+            let n_inv = Fr::from(n as u64).invert().unwrap();
+            evals.iter_mut().for_each(|eval| *eval *= n_inv);
+            assert_eq!(evals, coeffs);
+        }
+
+        // roots do not depend on the byte order of the field representation
+        fn check_root<F: WithSmallOrderMulGroup<3>>(log2_n: u32, log3_n: u32) {
+            let n = (1 << log2_n) * 3u64.pow(log3_n);
+            let omega = super::mixed_radix_root_of_unity::<F>(log2_n, log3_n).unwrap();
+            assert_eq!(omega.pow_vartime([n]), F::ONE);
+            assert_ne!(omega.pow_vartime([n / 3]), F::ONE);
+            if log2_n > 0 {
+                assert_ne!(omega.pow_vartime([n / 2]), F::ONE);
+            }
+        }
+        check_root::<crate::pluto_eris::Fq>(4, 1);
+        check_root::<crate::secp256k1::Fp>(1, 1);
+
+        // power of two sizes match best_fft
+        let omega = super::mixed_radix_root_of_unity::<Fr>(6, 0).unwrap();
+        let mut a: Vec<_> = (0..64).map(|_| Fr::random(OsRng)).collect();
+        let mut b = a.clone();
+        super::mixed_radix_fft(&mut a, omega, 6, 0);
+        best_fft(&mut b, omega, 6);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_fft_batch() {
+        let domain = EvaluationDomain::<Fr>::new(6);
+        let columns: Vec<Vec<_>> = (0..5)
+            .map(|_| (0..domain.size()).map(|_| Fr::random(OsRng)).collect())
+            .collect();
+
+        let mut batch = columns.clone();
+        super::best_fft_batch(&mut batch, domain.omega(), domain.k());
+        for (column, transformed) in columns.iter().zip(batch.iter()) {
+            let mut expected = column.clone();
+            best_fft(&mut expected, domain.omega(), domain.k());
+            assert_eq!(*transformed, expected);
+        }
+        domain.ifft_batch(&mut batch);
+        assert_eq!(batch, columns);
+
+        // column-major matrix
+        let mut matrix: Vec<_> = columns.concat();
+        let mut slices: Vec<&mut [Fr]> = matrix.chunks_mut(domain.size()).collect();
+        domain.fft_batch(&mut slices);
+        domain.ifft_batch(&mut slices);
+        assert_eq!(matrix, columns.concat());
+
+        let points: Vec<Vec<_>> = (0..3)
+            .map(|_| (0..domain.size()).map(|_| G1::random(OsRng)).collect())
+            .collect();
+        let mut batch = points.clone();
+        domain.fft_batch(&mut batch);
+        let mut expected = points[1].clone();
+        domain.fft(&mut expected);
+        assert_eq!(batch[1], expected);
+        domain.ifft_batch(&mut batch);
+        assert_eq!(batch, points);
+    }
+
+    #[test]
+    fn test_srs_monomial_to_lagrange() {
+        use crate::bn256::G1Affine;
+        use group::Curve;
+
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let n = domain.size();
+        let tau = Fr::random(OsRng);
+        let g = G1Affine::generator();
+        // a longer monomial SRS than the domain, of which only n points are used
+        let monomial: Vec<G1Affine> = (0..n + 3)
+            .map(|i| (g * tau.pow_vartime([i as u64])).to_affine())
+            .collect();
+
+        let lagrange = super::srs_monomial_to_lagrange(&monomial, &domain);
+        assert_eq!(lagrange.len(), n);
+        // L_i(tau) = omega^i / n * (tau^n - 1) / (tau - omega^i)
+        let mut omega_i = Fr::ONE;
+        for point in lagrange.iter() {
+            let l_i = omega_i
+                * domain.n_inv()
+                * domain.evaluate_vanishing_polynomial(tau)
+                * (tau - omega_i).invert().unwrap();
+            assert_eq!(*point, (g * l_i).to_affine());
+            omega_i *= domain.omega();
+        }
+
+        let roundtrip = super::srs_lagrange_to_monomial(&lagrange, &domain);
+        assert_eq!(roundtrip, monomial[..n]);
+    }
+
+    fn run_lde<F: ff::PrimeField>() {
+        use super::{lde, LdeInput};
+
+        for log_n in [0, 1, 3, 6] {
+            let domain = EvaluationDomain::<F>::new(log_n);
+            let coeffs: Vec<_> = (0..domain.size()).map(|_| F::random(OsRng)).collect();
+            let mut evals = coeffs.clone();
+            domain.fft(&mut evals);
+
+            for blowup in [1usize, 2, 4, 8, 16] {
+                let shift = F::random(OsRng);
+                let extended_domain = EvaluationDomain::<F>::new(log_n + blowup.ilog2());
+                let mut expected = coeffs.clone();
+                expected.resize(extended_domain.size(), F::ZERO);
+                let mut shift_i = F::ONE;
+                for c in expected.iter_mut() {
+                    *c *= shift_i;
+                    shift_i *= shift;
+                }
+                extended_domain.fft(&mut expected);
+
+                assert_eq!(
+                    lde(LdeInput::Coefficients(&coeffs), blowup, shift),
+                    expected
+                );
+                assert_eq!(lde(LdeInput::Evaluations(&evals), blowup, shift), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lde() {
+        run_lde::<Fr>();
+        run_lde::<crate::bls12_381::Fr>();
+        run_lde::<crate::pasta::Fp>();
+        run_lde::<crate::pluto_eris::Fq>();
+    }
+
+    #[test]
+    fn test_bit_reverse_permutation() {
+        for log_n in 0..=13 {
+            let n = 1usize << log_n;
+            let mut a: Vec<usize> = (0..n).collect();
+            super::bit_reverse_permutation(&mut a);
+            for (i, a) in a.iter().enumerate() {
+                assert_eq!(*a, super::bitreverse(i, log_n as usize));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_orderings() {
+        use super::{best_fft_ordered, bit_reverse_permutation, FftOrder};
+
+        for log_n in [0, 1, 2, 5, 10] {
+            let domain = EvaluationDomain::<Fr>::new(log_n);
+            let coeffs: Vec<_> = (0..domain.size()).map(|_| Fr::random(OsRng)).collect();
+            let mut coeffs_bit_reversed = coeffs.clone();
+            bit_reverse_permutation(&mut coeffs_bit_reversed);
+            let mut evals = coeffs.clone();
+            best_fft(&mut evals, domain.omega(), log_n);
+            let mut evals_bit_reversed = evals.clone();
+            bit_reverse_permutation(&mut evals_bit_reversed);
+
+            for input in [FftOrder::Natural, FftOrder::BitReversed] {
+                for output in [FftOrder::Natural, FftOrder::BitReversed] {
+                    let mut a = match input {
+                        FftOrder::Natural => coeffs.clone(),
+                        FftOrder::BitReversed => coeffs_bit_reversed.clone(),
+                    };
+                    best_fft_ordered(&mut a, domain.omega(), log_n, input, output);
+                    let expected = match output {
+                        FftOrder::Natural => &evals,
+                        FftOrder::BitReversed => &evals_bit_reversed,
+                    };
+                    assert_eq!(a, *expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_best_fft_on_disk() {
+        use crate::serde::SerdeObject;
+        use std::io::Cursor;
+
+        for (log_n, max_elements) in [(0, 1), (1, 2), (5, 8), (10, 32), (11, 100), (12, 1 << 12)] {
+            let domain = EvaluationDomain::<Fr>::new(log_n);
+            let coeffs: Vec<_> = (0..domain.size()).map(|_| Fr::random(OsRng)).collect();
+            let mut expected = coeffs.clone();
+            best_fft(&mut expected, domain.omega(), log_n);
+
+            let mut input = Cursor::new(Vec::new());
+            for coeff in coeffs.iter() {
+                coeff.write_raw(&mut input).unwrap();
+            }
+            let mut output = Cursor::new(Vec::new());
+            super::best_fft_on_disk(&mut input, &mut output, domain.omega(), log_n, max_elements)
+                .unwrap();
+
+            output.set_position(0);
+            let evals: Vec<_> = (0..domain.size())
+                .map(|_| Fr::read_raw(&mut output).unwrap())
+                .collect();
+            assert_eq!(evals, expected);
+        }
+    }
+
+    #[test]
+    fn test_evaluation_domain_group() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let points: Vec<_> = (0..domain.size()).map(|_| G1::random(OsRng)).collect();
+        let mut transformed = points.clone();
+        domain.coset_fft(&mut transformed);
+        domain.coset_ifft(&mut transformed);
+        assert_eq!(transformed, points);
+    }
+}
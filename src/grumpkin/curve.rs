@@ -8,7 +8,10 @@ use crate::group::Curve;
 use crate::group::{prime::PrimeCurveAffine, Group, GroupEncoding};
 use crate::grumpkin::Fq;
 use crate::grumpkin::Fr;
-use crate::hash_to_curve::svdw_hash_to_curve;
+use crate::hash_to_curve::{
+    hash_to_field, svdw_hash_to_curve, svdw_map_to_curve, svdw_precomputed_constants,
+    ExpandMessage, HashToCurveExt,
+};
 use crate::{
     endo, impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
     impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
@@ -89,6 +92,23 @@ impl G1 {
     const SVDW_Z: Fq = Fq::ONE;
 }
 
+/// Grumpkin has no suite in RFC 9380 nor in other libraries to interoperate with. This
+/// one instantiates the generic SvdW map of section 6.6.1 with `Z = 1`, so its outputs
+/// are specific to this crate.
+impl HashToCurveExt for G1 {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fq]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    fn map_to_curve(u: &Fq) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fq; 4] = svdw_precomputed_constants::<G1>(G1::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, G1::SVDW_Z)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::arithmetic::CurveEndo;
@@ -100,6 +120,7 @@ mod tests {
     #[test]
     fn test_hash_to_curve() {
         crate::tests::curve::hash_to_curve_test::<G1>();
+        crate::tests::curve::hash_to_curve_with_test::<G1>();
     }
 
     #[test]
@@ -1,5 +1,10 @@
 #![allow(clippy::op_ref)]
 
+use digest::{
+    consts::{U128, U64},
+    core_api::BlockSizeUser,
+    FixedOutput, HashMarker, Output, OutputSizeUser, Update,
+};
 use ff::{Field, FromUniformBytes, PrimeField};
 use pasta_curves::arithmetic::CurveExt;
 use static_assertions::const_assert;
@@ -7,14 +12,185 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::ff_ext::Legendre;
 
-/// Hashes over a message and writes the output to all of `buf`.
+mod expand_msg;
+pub use self::expand_msg::{
+    ExpandMessage, ExpandMessageState, ExpandMsgXmd, ExpandMsgXof, InitExpandMessage,
+};
+
+/// BLAKE2b with a 64-byte output, exposed as a [`Digest`](digest::Digest) so that it can
+/// be used as `ExpandMsgXmd<Blake2b512>`.
+#[derive(Clone, Debug)]
+pub struct Blake2b512(blake2b_simd::State);
+
+impl Default for Blake2b512 {
+    fn default() -> Self {
+        Self(blake2b_simd::Params::new().hash_length(64).to_state())
+    }
+}
+
+impl HashMarker for Blake2b512 {}
+
+impl OutputSizeUser for Blake2b512 {
+    type OutputSize = U64;
+}
+
+impl BlockSizeUser for Blake2b512 {
+    type BlockSize = U128;
+}
+
+impl Update for Blake2b512 {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+impl FixedOutput for Blake2b512 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(self.0.finalize().as_bytes());
+    }
+}
+
+/// The `sgn0` function of [section 4.1 of RFC 9380][sgn0], which the maps to curves use
+/// to pick one of the two square roots of an element.
+///
+/// Prime fields keep the default, the parity of the canonical representative. Extension
+/// fields must override it, as it is defined over all their coefficients.
+///
+/// [sgn0]: https://datatracker.ietf.org/doc/html/rfc9380#section-4.1
+pub trait Sgn0: PrimeField {
+    /// Returns the "sign" of the element, either `0` or `1`.
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+/// Curves that can be hashed to with any [`ExpandMessage`] variant.
+///
+/// Implements the `hash_to_curve` and `encode_to_curve` constructions of
+/// [RFC 9380](https://datatracker.ietf.org/doc/html/rfc9380#section-3). Unlike
+/// [`CurveExt::hash_to_curve`], which uses BLAKE2b, a DST derived from the curve id and
+/// 64 bytes per prime field coefficient, the caller chooses both the expander and the
+/// full domain separation tag, and coefficients take the `L` bytes of [`hash_to_field`],
+/// so the output matches other implementations of the same suite.
+pub trait HashToCurveExt: CurveExt {
+    /// Hashes `message` into `output.len()` elements of the base field.
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Self::Base]);
+
+    /// Maps a base field element to a point on the curve.
+    fn map_to_curve(u: &Self::Base) -> Self;
+
+    /// Sends a point on the curve to its prime order subgroup. Curves of prime order
+    /// keep the default, which returns the point unchanged.
+    fn clear_h(&self) -> Self {
+        *self
+    }
+
+    /// Implements a uniform encoding from byte strings to points of `Self`.
+    fn hash_to_curve_with<X: ExpandMessage>(message: &[u8], dst: &[u8]) -> Self {
+        let mut us = [Self::Base::ZERO; 2];
+        Self::hash_to_field::<X>(message, dst, &mut us);
+        let r = (Self::map_to_curve(&us[0]) + Self::map_to_curve(&us[1])).clear_h();
+        debug_assert!(bool::from(r.is_on_curve()));
+        r
+    }
+
+    /// Implements a **non-uniform** encoding from byte strings to points of `Self`.
+    fn encode_to_curve_with<X: ExpandMessage>(message: &[u8], dst: &[u8]) -> Self {
+        let mut us = [Self::Base::ZERO; 1];
+        Self::hash_to_field::<X>(message, dst, &mut us);
+        Self::map_to_curve(&us[0]).clear_h()
+    }
+}
+
+/// Hashes `message` into `output.len()` elements of a prime field.
+///
+/// Implements [section 5.2 of RFC 9380][hash_to_field] with `m = 1` and `k = 128`, so each
+/// element is reduced from `L = ceil((ceil(log2(p)) + 128) / 8)` bytes of output of `X`.
+///
+/// [hash_to_field]: https://datatracker.ietf.org/doc/html/rfc9380#section-5.2
+pub fn hash_to_field<F, X>(message: &[u8], dst: &[u8], output: &mut [F])
+where
+    F: PrimeField + FromUniformBytes<64>,
+    X: ExpandMessage,
+{
+    let len_per_elm = (F::NUM_BITS as usize + 128 + 7) / 8;
+    expand_to_field::<F, X>(message, dst, len_per_elm, output);
+}
+
+fn expand_to_field<F, X>(message: &[u8], dst: &[u8], len_per_elm: usize, output: &mut [F])
+where
+    F: FromUniformBytes<64>,
+    X: ExpandMessage,
+{
+    let mut expander = X::init_expand(message, dst, output.len() * len_per_elm);
+    let mut okm = vec![0u8; len_per_elm];
+    for out in output.iter_mut() {
+        expander.read_into(&mut okm);
+        *out = from_okm(&okm);
+    }
+}
+
+/// Interprets `okm` as a big endian integer and reduces it into a field element.
+fn from_okm<F: FromUniformBytes<64>>(okm: &[u8]) -> F {
+    if okm.len() <= 64 {
+        let mut little = [0u8; 64];
+        little
+            .iter_mut()
+            .zip(okm.iter().rev())
+            .for_each(|(l, b)| *l = *b);
+        F::from_uniform_bytes(&little)
+    } else {
+        // okm = hi * 2^256 + lo
+        let (hi, lo) = okm.split_at(okm.len() - 32);
+        let mut shift = [0u8; 64];
+        shift[32] = 1;
+        from_okm::<F>(hi) * F::from_uniform_bytes(&shift) + from_okm::<F>(lo)
+    }
+}
+
+/// [`CurveExt::hash_to_curve`] for curves that implement [`HashToCurveExt`], using
+/// `expand_message_xmd` with BLAKE2b and a DST derived from the curve id.
+///
+/// Like every [`CurveExt::hash_to_curve`] of this crate, each prime field coefficient is
+/// reduced from 64 bytes of output, see [`blake2b_hash_to_field`], rather than from the
+/// `L` bytes of [`hash_to_field`] used by [`HashToCurveExt::hash_to_curve_with`]. Base
+/// fields of degree `M` over `F` are built with `from_coeffs` from `M` consecutive
+/// coefficients.
+#[allow(clippy::type_complexity)]
+pub(crate) fn blake2b_hash_to_curve<'a, C, F, const M: usize>(
+    method: &'static str,
+    curve_id: &'static str,
+    domain_prefix: &'a str,
+    from_coeffs: fn([F; M]) -> C::Base,
+) -> Box<dyn Fn(&[u8]) -> C + 'a>
+where
+    C: HashToCurveExt,
+    F: FromUniformBytes<64>,
+{
+    Box::new(move |message| {
+        let mut coeffs = vec![F::ZERO; 2 * M];
+        blake2b_hash_to_field(method, curve_id, domain_prefix, message, &mut coeffs);
+
+        let [q0, q1]: [C; 2] = [0, 1].map(|i| {
+            let u = from_coeffs(coeffs[i * M..(i + 1) * M].try_into().unwrap());
+            C::map_to_curve(&u)
+        });
+
+        let r = (q0 + &q1).clear_h();
+        debug_assert!(bool::from(r.is_on_curve()));
+        r
+    })
+}
+
+/// Hashes over a message and writes the output to all of `buf`, using
+/// `expand_message_xmd` with BLAKE2b and a DST derived from the curve id.
 /// Modified from https://github.com/zcash/pasta_curves/blob/7e3fc6a4919f6462a32b79dd226cb2587b7961eb/src/hashtocurve.rs#L11.
-fn hash_to_field<F: FromUniformBytes<64>>(
+fn blake2b_hash_to_field<F: FromUniformBytes<64>>(
     method: &str,
     curve_id: &str,
     domain_prefix: &str,
     message: &[u8],
-    buf: &mut [F; 2],
+    buf: &mut [F],
 ) {
     assert!(domain_prefix.len() < 256);
     assert!((18 + method.len() + curve_id.len() + domain_prefix.len()) < 256);
@@ -24,65 +200,8 @@ fn hash_to_field<F: FromUniformBytes<64>>(
     const CHUNKLEN: usize = 64;
     const_assert!(CHUNKLEN * 2 < 256);
 
-    // Input block size of BLAKE2b.
-    const R_IN_BYTES: usize = 128;
-
-    let personal = [0u8; 16];
-    let empty_hasher = blake2b_simd::Params::new()
-        .hash_length(CHUNKLEN)
-        .personal(&personal)
-        .to_state();
-
-    let b_0 = empty_hasher
-        .clone()
-        .update(&[0; R_IN_BYTES])
-        .update(message)
-        .update(&[0, (CHUNKLEN * 2) as u8, 0])
-        .update(domain_prefix.as_bytes())
-        .update(b"-")
-        .update(curve_id.as_bytes())
-        .update(b"_XMD:BLAKE2b_")
-        .update(method.as_bytes())
-        .update(b"_RO_")
-        .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-        .finalize();
-
-    let b_1 = empty_hasher
-        .clone()
-        .update(b_0.as_array())
-        .update(&[1])
-        .update(domain_prefix.as_bytes())
-        .update(b"-")
-        .update(curve_id.as_bytes())
-        .update(b"_XMD:BLAKE2b_")
-        .update(method.as_bytes())
-        .update(b"_RO_")
-        .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-        .finalize();
-
-    let b_2 = {
-        let mut empty_hasher = empty_hasher;
-        for (l, r) in b_0.as_array().iter().zip(b_1.as_array().iter()) {
-            empty_hasher.update(&[*l ^ *r]);
-        }
-        empty_hasher
-            .update(&[2])
-            .update(domain_prefix.as_bytes())
-            .update(b"-")
-            .update(curve_id.as_bytes())
-            .update(b"_XMD:BLAKE2b_")
-            .update(method.as_bytes())
-            .update(b"_RO_")
-            .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-            .finalize()
-    };
-
-    for (big, buf) in [b_1, b_2].iter().zip(buf.iter_mut()) {
-        let mut little = [0u8; CHUNKLEN];
-        little.copy_from_slice(big.as_array());
-        little.reverse();
-        *buf = F::from_uniform_bytes(&little);
-    }
+    let dst = format!("{domain_prefix}-{curve_id}_XMD:BLAKE2b_{method}_RO_");
+    expand_to_field::<F, ExpandMsgXmd<Blake2b512>>(message, dst.as_bytes(), CHUNKLEN, buf);
 }
 
 // Implementation of <https://datatracker.ietf.org/doc/html/rfc9380#name-simplified-swu-method>
@@ -90,11 +209,19 @@ fn hash_to_field<F: FromUniformBytes<64>>(
 pub(crate) fn simple_svdw_map_to_curve<C>(u: C::Base, z: C::Base) -> C
 where
     C: CurveExt,
+    C::Base: Sgn0,
 {
-    let zero = C::Base::ZERO;
-    let one = C::Base::ONE;
-    let a = C::a();
-    let b = C::b();
+    let (x, y) = simple_svdw_map_to_xy(u, C::a(), C::b(), z);
+    C::new_jacobian(x, y, C::Base::ONE).unwrap()
+}
+
+/// Simplified SWU map onto `y^2 = x^3 + a * x + b`, returning affine coordinates.
+///
+/// This is split out of [`simple_svdw_map_to_curve`] for curves with `a * b == 0`, such
+/// as secp256k1, which map onto an isogenous curve that has no type of its own.
+pub(crate) fn simple_svdw_map_to_xy<F: Sgn0>(u: F, a: F, b: F, z: F) -> (F, F) {
+    let zero = F::ZERO;
+    let one = F::ONE;
 
     //1.  tv1 = u^2
     let tv1 = u.square();
@@ -110,7 +237,7 @@ where
     let tv3 = b * tv3;
     //7.  tv4 = CMOV(Z, -tv2, tv2 != 0) # tv4 = z if tv2 is 0 else tv4 = -tv2
     let tv2_is_not_zero = !tv2.ct_eq(&zero);
-    let tv4 = C::Base::conditional_select(&z, &-tv2, tv2_is_not_zero);
+    let tv4 = F::conditional_select(&z, &-tv2, tv2_is_not_zero);
     //8.  tv4 = A * tv4
     let tv4 = a * tv4;
     //9.  tv2 = tv3^2
@@ -138,17 +265,17 @@ where
     //20.   y = y * y1
     let y = y * y1;
     //21.   x = CMOV(x, tv3, is_gx1_square)
-    let x = C::Base::conditional_select(&x, &tv3, is_gx1_square);
+    let x = F::conditional_select(&x, &tv3, is_gx1_square);
     //22.   y = CMOV(y, y1, is_gx1_square)
-    let y = C::Base::conditional_select(&y, &y1, is_gx1_square);
+    let y = F::conditional_select(&y, &y1, is_gx1_square);
     //23.  e1 = sgn0(u) == sgn0(y)
-    let e1 = u.is_odd().ct_eq(&y.is_odd());
+    let e1 = u.sgn0().ct_eq(&y.sgn0());
     //24.   y = CMOV(-y, y, e1) # Select correct sign of y
-    let y = C::Base::conditional_select(&-y, &y, e1);
+    let y = F::conditional_select(&-y, &y, e1);
     //25.   x = x / tv4
     let x = x * tv4.invert().unwrap();
     //26. return (x, y)
-    C::new_jacobian(x, y, one).unwrap()
+    (x, y)
 }
 
 #[allow(clippy::type_complexity)]
@@ -159,11 +286,11 @@ pub(crate) fn simple_svdw_hash_to_curve<'a, C>(
 ) -> Box<dyn Fn(&[u8]) -> C + 'a>
 where
     C: CurveExt,
-    C::Base: FromUniformBytes<64>,
+    C::Base: FromUniformBytes<64> + Sgn0,
 {
     Box::new(move |message| {
         let mut us = [C::Base::ZERO; 2];
-        hash_to_field("SSWU", curve_id, domain_prefix, message, &mut us);
+        blake2b_hash_to_field("SSWU", curve_id, domain_prefix, message, &mut us);
 
         let [q0, q1]: [C; 2] = us.map(|u| simple_svdw_map_to_curve(u, z));
 
@@ -184,7 +311,7 @@ pub(crate) fn svdw_map_to_curve<C>(
 ) -> C
 where
     C: CurveExt,
-    C::Base: Legendre,
+    C::Base: Legendre + Sgn0,
 {
     let one = C::Base::ONE;
     let a = C::a();
@@ -257,7 +384,7 @@ where
     // 33. y = sqrt(gx)
     let y = gx.sqrt().unwrap();
     // 34. e3 = sgn0(u) == sgn0(y)
-    let e3 = u.is_odd().ct_eq(&y.is_odd());
+    let e3 = u.sgn0().ct_eq(&y.sgn0());
     // 35. y = CMOV(-y, y, e3)    # Select correct sign of y
     let y = C::Base::conditional_select(&-y, &y, e3);
     // 36. return (x, y)
@@ -310,13 +437,13 @@ pub(crate) fn svdw_hash_to_curve<'a, C>(
 ) -> Box<dyn Fn(&[u8]) -> C + 'a>
 where
     C: CurveExt,
-    C::Base: FromUniformBytes<64> + Legendre,
+    C::Base: FromUniformBytes<64> + Legendre + Sgn0,
 {
     let [c1, c2, c3, c4] = svdw_precomputed_constants::<C>(z);
 
     Box::new(move |message| {
         let mut us = [C::Base::ZERO; 2];
-        hash_to_field("SVDW", curve_id, domain_prefix, message, &mut us);
+        blake2b_hash_to_field("SVDW", curve_id, domain_prefix, message, &mut us);
 
         let [q0, q1]: [C; 2] = us.map(|u| svdw_map_to_curve(u, c1, c2, c3, c4, z));
 
@@ -326,7 +453,11 @@ where
     })
 }
 
-pub(crate) fn svdw_precomputed_constants<C: CurveExt>(z: C::Base) -> [C::Base; 4] {
+pub(crate) fn svdw_precomputed_constants<C>(z: C::Base) -> [C::Base; 4]
+where
+    C: CurveExt,
+    C::Base: Sgn0,
+{
     let a = C::a();
     let b = C::b();
     let one = C::Base::ONE;
@@ -341,7 +472,7 @@ pub(crate) fn svdw_precomputed_constants<C: CurveExt>(z: C::Base) -> [C::Base; 4
     // 3. c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A))    # sgn0(c3) MUST equal 0
     let c3 = {
         let c3 = (-c1 * tmp).sqrt().unwrap();
-        C::Base::conditional_select(&c3, &-c3, c3.is_odd())
+        C::Base::conditional_select(&c3, &-c3, c3.sgn0())
     };
     // 4. c4 = -4 * g(Z) / (3 * Z^2 + 4 * A)
     let c4 = -four * c1 * tmp.invert().unwrap();
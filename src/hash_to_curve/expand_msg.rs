@@ -10,7 +10,7 @@ use core::{
 
 use digest::{crypto_common::BlockSizeUser, Digest, ExtendableOutput, Update, XofReader};
 
-use crate::bls12_381::generic_array::{
+use digest::generic_array::{
     typenum::{Unsigned, U32},
     ArrayLength, GenericArray,
 };
@@ -3,6 +3,7 @@ pub mod ff_ext;
 pub mod fft;
 pub mod hash_to_curve;
 pub mod msm;
+pub mod poly;
 pub mod serde;
 
 pub mod bls12_381;
@@ -19,7 +20,7 @@ pub mod secq256k1;
 mod derive;
 
 // Re-export to simplify down stream dependencies
-pub use arithmetic::CurveAffineExt;
+pub use arithmetic::{CurveAffineExt, CurveEndo};
 pub use ff;
 pub use group;
 pub use pairing;
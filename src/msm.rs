@@ -1,12 +1,22 @@
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
 use std::ops::Neg;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 
-use crate::CurveAffine;
+use crate::arithmetic::CurveEndo;
+use crate::serde::SerdeObject;
+use crate::{CurveAffine, CurveExt};
 use ff::Field;
 use ff::PrimeField;
-use group::Group;
+use group::{Curve, Group};
+use rand_core::RngCore;
 use rayon::iter::{
-    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelIterator,
 };
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+use subtle::{Choice, ConstantTimeEq};
 
 const BATCH_SIZE: usize = 64;
 
@@ -201,6 +211,13 @@ impl<C: CurveAffine> Bucket<C> {
         }
     }
 
+    fn eval(&self) -> C::Curve {
+        match self {
+            Self::Point(this) => *this,
+            Self::None => C::Curve::identity(),
+        }
+    }
+
     fn add(&self, other: &BucketAffine<C>) -> C::Curve {
         match (self, other) {
             (Self::Point(this), BucketAffine::Point(other)) => *this + other.eval(),
@@ -267,7 +284,7 @@ impl<C: CurveAffine> BucketAffine<C> {
 
 struct Schedule<C: CurveAffine> {
     buckets: Vec<BucketAffine<C>>,
-    set: [SchedulePoint; BATCH_SIZE],
+    set: Vec<SchedulePoint>,
     ptr: usize,
 }
 
@@ -289,12 +306,8 @@ impl SchedulePoint {
 }
 
 impl<C: CurveAffine> Schedule<C> {
-    fn new(c: usize) -> Self {
-        let set = (0..BATCH_SIZE)
-            .map(|_| SchedulePoint::default())
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
+    fn new(c: usize, batch_size: usize) -> Self {
+        let set = vec![SchedulePoint::default(); batch_size];
 
         Self {
             buckets: vec![BucketAffine::None; 1 << (c - 1)],
@@ -329,25 +342,397 @@ impl<C: CurveAffine> Schedule<C> {
     }
 }
 
+/// Default window size for a bucket method over `num_bases` points.
+fn default_window(num_bases: usize) -> usize {
+    // TODO: consider adjusting it with emprical data?
+    if num_bases < 4 {
+        1
+    } else if num_bases < 32 {
+        3
+    } else {
+        (f64::from(num_bases as u32)).ln().ceil() as usize
+    }
+}
+
+/// Coordinate model of a curve, which decides how MSM buckets are accumulated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveModel {
+    /// `y^2 = x^3 + ax + b`. Buckets can be accumulated with batched affine additions,
+    /// which share one inversion per batch.
+    ShortWeierstrass,
+    /// `ax^2 + y^2 = 1 + dx^2y^2`. Buckets are accumulated in extended coordinates,
+    /// whose unified additions need no inversion.
+    TwistedEdwards,
+}
+
+impl CurveModel {
+    /// Detects the model of `C` from its negation map, which is `-(x, y) = (x, -y)`
+    /// on short Weierstrass curves and `-(x, y) = (-x, y)` on twisted Edwards curves.
+    pub fn of<C: CurveAffine>() -> Self {
+        let g = C::generator().coordinates().unwrap();
+        let neg_g = (-C::generator()).coordinates().unwrap();
+        if g.x() == neg_g.x() {
+            Self::ShortWeierstrass
+        } else {
+            Self::TwistedEdwards
+        }
+    }
+}
+
+/// Bucket method variants [`msm_with_config`] can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MsmAlgorithm {
+    /// [`MsmAlgorithm::Parallel`] for windows smaller than 10 bits and
+    /// [`MsmAlgorithm::BatchAffine`] otherwise.
+    #[default]
+    Auto,
+    /// Single-threaded, with projective buckets. See [`msm_serial`].
+    Serial,
+    /// Bases split in one chunk per thread, with projective buckets. See [`msm_parallel`].
+    Parallel,
+    /// One task per window, with buckets accumulated by batched affine additions
+    /// sharing a single inversion. On twisted Edwards curves the buckets are kept in
+    /// extended coordinates instead, see [`CurveModel`].
+    BatchAffine,
+}
+
+impl MsmAlgorithm {
+    const ALL: [Self; 4] = [Self::Auto, Self::Serial, Self::Parallel, Self::BatchAffine];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Serial => "serial",
+            Self::Parallel => "parallel",
+            Self::BatchAffine => "batch_affine",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.name() == name)
+    }
+}
+
+/// Parameters of a multi-scalar multiplication, see [`msm_with_config`].
+///
+/// The default config is the one [`msm_best`] runs with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsmConfig {
+    /// Window size in bits, in `1..=24`. Picked from the number of bases if `None`.
+    pub window: Option<usize>,
+    /// Number of bucket additions sharing one inversion in [`MsmAlgorithm::BatchAffine`].
+    pub batch_size: usize,
+    /// Number of threads to run on. The current rayon thread pool is used if `None`,
+    /// otherwise a pool built once per thread count and shared across calls.
+    pub num_threads: Option<usize>,
+    /// Bucket method to run.
+    pub algorithm: MsmAlgorithm,
+}
+
+impl Default for MsmConfig {
+    fn default() -> Self {
+        Self {
+            window: None,
+            batch_size: BATCH_SIZE,
+            num_threads: None,
+            algorithm: MsmAlgorithm::Auto,
+        }
+    }
+}
+
+impl MsmConfig {
+    fn assert_valid(&self) {
+        if let Some(window) = self.window {
+            assert!((1..=24).contains(&window), "window must be in 1..=24");
+        }
+        assert!(self.batch_size > 0, "batch size must be positive");
+        assert_ne!(
+            self.num_threads,
+            Some(0),
+            "number of threads must be positive"
+        );
+    }
+
+    /// Resolves [`MsmAlgorithm::Auto`] for `num_bases` points.
+    fn algorithm(&self, num_bases: usize) -> MsmAlgorithm {
+        match self.algorithm {
+            MsmAlgorithm::Auto => {
+                let c = self.window.unwrap_or_else(|| default_window(num_bases));
+                if c < 10 {
+                    MsmAlgorithm::Parallel
+                } else {
+                    MsmAlgorithm::BatchAffine
+                }
+            }
+            algorithm => algorithm,
+        }
+    }
+}
+
+/// Returns a thread pool with `num_threads` threads, which is built on first use and
+/// shared by all MSMs configured with the same number of threads.
+fn thread_pool(num_threads: usize) -> Arc<rayon::ThreadPool> {
+    lazy_static::lazy_static! {
+        static ref POOLS: Mutex<HashMap<usize, Arc<rayon::ThreadPool>>> =
+            Mutex::new(HashMap::new());
+    }
+
+    let mut pools = POOLS.lock().unwrap();
+    pools
+        .entry(num_threads)
+        .or_insert_with(|| {
+            Arc::new(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .expect("failed to build the thread pool"),
+            )
+        })
+        .clone()
+}
+
+/// Per-curve MSM tuning profile, mapping MSM sizes to the fastest [`MsmConfig`]
+/// measured on this machine.
+///
+/// Profiles are measured once with [`MsmProfile::calibrate`] and persisted with
+/// [`MsmProfile::save`], e.g.
+///
+/// ```ignore
+/// let profile = MsmProfile::load_or_calibrate::<G1Affine>("msm_bn256.profile", 10..=20)?;
+/// let r = msm_with_config(&coeffs, &bases, &profile.config(coeffs.len()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MsmProfile {
+    curve_id: String,
+    // sorted by log2 of the MSM size
+    configs: Vec<(u32, MsmConfig)>,
+}
+
+impl MsmProfile {
+    /// Benchmarks MSMs of size `2^k` for every `k` in `log_sizes` over a range of
+    /// algorithms, window sizes and batch sizes, and keeps the fastest config per size.
+    ///
+    /// This runs dozens of MSMs per size, so it is meant to be run once per machine.
+    pub fn calibrate<C: CurveAffine>(log_sizes: impl IntoIterator<Item = u32>) -> Self {
+        use std::time::{Duration, Instant};
+
+        let mut configs: Vec<(u32, MsmConfig)> = Vec::new();
+        for log_n in log_sizes {
+            let n = 1usize << log_n;
+            let points: Vec<_> = (0..n)
+                .into_par_iter()
+                .map_init(rand::thread_rng, |rng, _| C::Curve::random(rng))
+                .collect();
+            let mut bases = vec![C::identity(); n];
+            C::Curve::batch_normalize(&points, &mut bases);
+            let coeffs: Vec<_> = (0..n)
+                .into_par_iter()
+                .map_init(rand::thread_rng, |rng, _| C::Scalar::random(rng))
+                .collect();
+
+            let c = default_window(n);
+            let windows = c.saturating_sub(2).max(1)..=(c + 2).min(20);
+            let candidates = windows.flat_map(|window| {
+                [
+                    (MsmAlgorithm::Parallel, BATCH_SIZE),
+                    (MsmAlgorithm::BatchAffine, 32),
+                    (MsmAlgorithm::BatchAffine, 64),
+                    (MsmAlgorithm::BatchAffine, 128),
+                ]
+                .map(|(algorithm, batch_size)| MsmConfig {
+                    window: Some(window),
+                    batch_size,
+                    num_threads: None,
+                    algorithm,
+                })
+            });
+
+            let mut best = (Duration::MAX, MsmConfig::default());
+            for config in candidates {
+                let start = Instant::now();
+                msm_with_config(&coeffs, &bases, &config);
+                let elapsed = start.elapsed();
+                if elapsed < best.0 {
+                    best = (elapsed, config);
+                }
+            }
+            configs.push((log_n, best.1));
+        }
+        configs.sort_by_key(|(log_n, _)| *log_n);
+        configs.dedup_by_key(|(log_n, _)| *log_n);
+
+        Self {
+            curve_id: C::CurveExt::CURVE_ID.to_string(),
+            configs,
+        }
+    }
+
+    /// Returns the id of the curve the profile was measured for.
+    pub fn curve_id(&self) -> &str {
+        &self.curve_id
+    }
+
+    /// Returns the config measured for the closest size not larger than `n`, or the
+    /// smallest measured size if `n` is below all of them.
+    pub fn config(&self, n: usize) -> MsmConfig {
+        let log_n = n.max(1).ilog2();
+        self.configs
+            .iter()
+            .rev()
+            .find(|(k, _)| *k <= log_n)
+            .or(self.configs.first())
+            .map_or_else(MsmConfig::default, |(_, config)| *config)
+    }
+
+    /// Writes the profile in a line-based text format.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "curve {}", self.curve_id)?;
+        for (log_n, config) in self.configs.iter() {
+            let or_auto = |v: Option<usize>| v.map_or("auto".to_string(), |v| v.to_string());
+            writeln!(
+                writer,
+                "{} {} {} {} {}",
+                log_n,
+                config.algorithm.name(),
+                or_auto(config.window),
+                config.batch_size,
+                or_auto(config.num_threads)
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Reads a profile written with [`MsmProfile::write`].
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+
+        let curve_id = lines
+            .next()
+            .and_then(|line| line.strip_prefix("curve "))
+            .ok_or_else(|| invalid("missing curve id"))?
+            .trim()
+            .to_string();
+
+        let mut configs = Vec::new();
+        for line in lines {
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let [log_n, algorithm, window, batch_size, num_threads] = fields[..] else {
+                return Err(invalid("malformed profile entry"));
+            };
+            let or_auto = |v: &str| -> io::Result<Option<usize>> {
+                match v {
+                    "auto" => Ok(None),
+                    v => v.parse().map(Some).map_err(|_| invalid("invalid number")),
+                }
+            };
+            let config = MsmConfig {
+                window: or_auto(window)?,
+                batch_size: batch_size
+                    .parse()
+                    .map_err(|_| invalid("invalid batch size"))?,
+                num_threads: or_auto(num_threads)?,
+                algorithm: MsmAlgorithm::from_name(algorithm)
+                    .ok_or_else(|| invalid("unknown algorithm"))?,
+            };
+            if config.window.is_some_and(|w| !(1..=24).contains(&w))
+                || config.batch_size == 0
+                || config.num_threads == Some(0)
+            {
+                return Err(invalid("invalid config"));
+            }
+            let log_n = log_n.parse().map_err(|_| invalid("invalid size"))?;
+            configs.push((log_n, config));
+        }
+        configs.sort_by_key(|(log_n, _)| *log_n);
+
+        Ok(Self { curve_id, configs })
+    }
+
+    /// Saves the profile to `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = std::fs::File::create(path)?;
+        self.write(&mut file)
+    }
+
+    /// Loads a profile saved with [`MsmProfile::save`].
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut file = std::fs::File::open(path)?;
+        Self::read(&mut file)
+    }
+
+    /// Loads the profile at `path` if it exists, otherwise calibrates a new one over
+    /// `log_sizes` and saves it to `path`.
+    ///
+    /// Returns an [`io::ErrorKind::InvalidData`] error rather than overwriting it if
+    /// the profile at `path` was measured for another curve than `C`.
+    pub fn load_or_calibrate<C: CurveAffine>(
+        path: impl AsRef<Path>,
+        log_sizes: impl IntoIterator<Item = u32>,
+    ) -> io::Result<Self> {
+        let path = path.as_ref();
+        match Self::load(path) {
+            Ok(profile) if profile.curve_id == C::CurveExt::CURVE_ID => Ok(profile),
+            Ok(profile) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "profile is for curve {}, not {}",
+                    profile.curve_id,
+                    C::CurveExt::CURVE_ID
+                ),
+            )),
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            Err(_) => {
+                let profile = Self::calibrate::<C>(log_sizes);
+                profile.save(path)?;
+                Ok(profile)
+            }
+        }
+    }
+}
+
 /// Performs a multi-scalar multiplication operation.
 ///
 /// This function will panic if coeffs and bases have a different length.
 pub fn msm_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C::Curve) {
     let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+    msm_serial_repr(&coeffs, bases, default_window(bases.len()), acc);
+}
 
-    let c = if bases.len() < 4 {
-        1
-    } else if bases.len() < 32 {
-        3
-    } else {
-        (f64::from(bases.len() as u32)).ln().ceil() as usize
-    };
+/// Same as [`msm_serial`], over little-endian byte representations of the scalars
+/// and with window size `c`.
+fn msm_serial_repr<C: CurveAffine, R: AsRef<[u8]>>(
+    coeffs: &[R],
+    bases: &[C],
+    c: usize,
+    acc: &mut C::Curve,
+) {
+    msm_serial_batch_repr(&[coeffs], bases, c, std::slice::from_mut(acc));
+}
 
-    let field_byte_size = C::Scalar::NUM_BITS.div_ceil(8u32) as usize;
+/// Same as [`msm_serial_repr`] for a batch of coefficient vectors against the same
+/// bases, which are streamed through once per window for the whole batch.
+fn msm_serial_batch_repr<C: CurveAffine, R: AsRef<[u8]>>(
+    coeffs: &[&[R]],
+    bases: &[C],
+    c: usize,
+    acc: &mut [C::Curve],
+) {
+    let field_byte_size = coeffs
+        .iter()
+        .flat_map(|coeffs| coeffs.iter())
+        .map(|coeff| coeff.as_ref().len())
+        .max()
+        .unwrap_or(0);
     // OR all coefficients in order to make a mask to figure out the maximum number of bytes used
     // among all coefficients.
     let mut acc_or = vec![0; field_byte_size];
-    for coeff in &coeffs {
+    for coeff in coeffs.iter().flat_map(|coeffs| coeffs.iter()) {
         for (acc_limb, limb) in acc_or.iter_mut().zip(coeff.as_ref().iter()) {
             *acc_limb |= *limb;
         }
@@ -363,62 +748,68 @@ pub fn msm_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C
     }
     let number_of_windows = max_byte_size * 8_usize / c + 1;
 
-    for current_window in (0..number_of_windows).rev() {
-        for _ in 0..c {
-            *acc = acc.double();
-        }
+    #[derive(Clone, Copy)]
+    enum Bucket<C: CurveAffine> {
+        None,
+        Affine(C),
+        Projective(C::Curve),
+    }
 
-        #[derive(Clone, Copy)]
-        enum Bucket<C: CurveAffine> {
-            None,
-            Affine(C),
-            Projective(C::Curve),
+    impl<C: CurveAffine> Bucket<C> {
+        fn add_assign(&mut self, other: &C) {
+            *self = match *self {
+                Bucket::None => Bucket::Affine(*other),
+                Bucket::Affine(a) => Bucket::Projective(a + *other),
+                Bucket::Projective(mut a) => {
+                    a += *other;
+                    Bucket::Projective(a)
+                }
+            }
         }
 
-        impl<C: CurveAffine> Bucket<C> {
-            fn add_assign(&mut self, other: &C) {
-                *self = match *self {
-                    Bucket::None => Bucket::Affine(*other),
-                    Bucket::Affine(a) => Bucket::Projective(a + *other),
-                    Bucket::Projective(mut a) => {
-                        a += *other;
-                        Bucket::Projective(a)
-                    }
+        fn add(self, mut other: C::Curve) -> C::Curve {
+            match self {
+                Bucket::None => other,
+                Bucket::Affine(a) => {
+                    other += a;
+                    other
                 }
+                Bucket::Projective(a) => other + a,
             }
+        }
+    }
 
-            fn add(self, mut other: C::Curve) -> C::Curve {
-                match self {
-                    Bucket::None => other,
-                    Bucket::Affine(a) => {
-                        other += a;
-                        other
-                    }
-                    Bucket::Projective(a) => other + a,
-                }
+    for current_window in (0..number_of_windows).rev() {
+        for acc in acc.iter_mut() {
+            for _ in 0..c {
+                *acc = acc.double();
             }
         }
 
-        let mut buckets: Vec<Bucket<C>> = vec![Bucket::None; 1 << (c - 1)];
+        let mut buckets: Vec<Vec<Bucket<C>>> = vec![vec![Bucket::None; 1 << (c - 1)]; coeffs.len()];
 
-        for (coeff, base) in coeffs.iter().zip(bases.iter()) {
-            let coeff = get_booth_index(current_window, c, coeff.as_ref());
-            if coeff.is_positive() {
-                buckets[coeff as usize - 1].add_assign(base);
-            }
-            if coeff.is_negative() {
-                buckets[coeff.unsigned_abs() as usize - 1].add_assign(&base.neg());
+        for (base_idx, base) in bases.iter().enumerate() {
+            for (coeffs, buckets) in coeffs.iter().zip(buckets.iter_mut()) {
+                let coeff = get_booth_index(current_window, c, coeffs[base_idx].as_ref());
+                if coeff.is_positive() {
+                    buckets[coeff as usize - 1].add_assign(base);
+                }
+                if coeff.is_negative() {
+                    buckets[coeff.unsigned_abs() as usize - 1].add_assign(&base.neg());
+                }
             }
         }
 
-        // Summation by parts
-        // e.g. 3a + 2b + 1c = a +
-        //                    (a) + b +
-        //                    ((a) + b) + c
-        let mut running_sum = C::Curve::identity();
-        for exp in buckets.into_iter().rev() {
-            running_sum = exp.add(running_sum);
-            *acc += &running_sum;
+        for (acc, buckets) in acc.iter_mut().zip(buckets) {
+            // Summation by parts
+            // e.g. 3a + 2b + 1c = a +
+            //                    (a) + b +
+            //                    ((a) + b) + c
+            let mut running_sum = C::Curve::identity();
+            for exp in buckets.into_iter().rev() {
+                running_sum = exp.add(running_sum);
+                *acc += &running_sum;
+            }
         }
     }
 }
@@ -431,28 +822,63 @@ pub fn msm_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C
 pub fn msm_parallel<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
     assert_eq!(coeffs.len(), bases.len());
 
+    let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+    msm_parallel_repr(&coeffs, bases, None)
+}
+
+/// Same as [`msm_parallel`], over little-endian byte representations of the scalars.
+///
+/// The window size is picked per chunk if `window` is `None`.
+fn msm_parallel_repr<C: CurveAffine, R: AsRef<[u8]> + Sync>(
+    coeffs: &[R],
+    bases: &[C],
+    window: Option<usize>,
+) -> C::Curve {
+    msm_parallel_batch_repr(&[coeffs], bases, window)[0]
+}
+
+/// Same as [`msm_parallel_repr`] for a batch of coefficient vectors against the same
+/// bases, each thread streaming through its chunk of the bases once for the whole batch.
+fn msm_parallel_batch_repr<C: CurveAffine, R: AsRef<[u8]> + Sync>(
+    coeffs: &[&[R]],
+    bases: &[C],
+    window: Option<usize>,
+) -> Vec<C::Curve> {
+    for coeffs in coeffs.iter() {
+        assert_eq!(coeffs.len(), bases.len());
+    }
+
     let num_threads = rayon::current_num_threads();
-    if coeffs.len() > num_threads {
-        let chunk = coeffs.len() / num_threads;
-        let num_chunks = coeffs.chunks(chunk).len();
-        let mut results = vec![C::Curve::identity(); num_chunks];
+    if bases.len() > num_threads {
+        let chunk = bases.len() / num_threads;
+        let num_chunks = bases.chunks(chunk).len();
+        let mut results = vec![vec![C::Curve::identity(); coeffs.len()]; num_chunks];
         rayon::scope(|scope| {
-            let chunk = coeffs.len() / num_threads;
-
-            for ((coeffs, bases), acc) in coeffs
-                .chunks(chunk)
-                .zip(bases.chunks(chunk))
-                .zip(results.iter_mut())
-            {
+            for ((i, bases), acc) in bases.chunks(chunk).enumerate().zip(results.iter_mut()) {
                 scope.spawn(move |_| {
-                    msm_serial(coeffs, bases, acc);
+                    let start = i * chunk;
+                    let coeffs: Vec<_> = coeffs
+                        .iter()
+                        .map(|coeffs| &coeffs[start..start + bases.len()])
+                        .collect();
+                    let c = window.unwrap_or_else(|| default_window(bases.len()));
+                    msm_serial_batch_repr(&coeffs, bases, c, acc);
                 });
             }
         });
-        results.iter().fold(C::Curve::identity(), |a, b| a + b)
+        results
+            .into_iter()
+            .reduce(|mut a, b| {
+                for (a, b) in a.iter_mut().zip(b) {
+                    *a += b;
+                }
+                a
+            })
+            .unwrap()
     } else {
-        let mut acc = C::Curve::identity();
-        msm_serial(coeffs, bases, &mut acc);
+        let mut acc = vec![C::Curve::identity(); coeffs.len()];
+        let c = window.unwrap_or_else(|| default_window(bases.len()));
+        msm_serial_batch_repr(coeffs, bases, c, &mut acc);
         acc
     }
 }
@@ -461,75 +887,1015 @@ pub fn msm_parallel<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Cur
 ///
 /// This will use multithreading if beneficial.
 pub fn msm_best<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
-    assert_eq!(coeffs.len(), bases.len());
+    msm_with_config(coeffs, bases, &MsmConfig::default())
+}
 
-    // TODO: consider adjusting it with emprical data?
-    let c = if bases.len() < 4 {
-        1
-    } else if bases.len() < 32 {
-        3
-    } else {
-        (f64::from(bases.len() as u32)).ln().ceil() as usize
-    };
+/// Performs a multi-scalar multiplication operation with explicit parameters.
+///
+/// This function will panic if coeffs and bases have a different length, or if
+/// `config` is invalid.
+pub fn msm_with_config<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    config: &MsmConfig,
+) -> C::Curve {
+    msm_routed(coeffs, bases, config, None)
+}
+
+/// Same as [`msm_with_config`], running full-width scalars through the GLV
+/// endomorphism if `glv` is given.
+fn msm_routed<C: CurveAffine>(
+    scalars: &[C::Scalar],
+    bases: &[C],
+    config: &MsmConfig,
+    glv: Option<Glv<C>>,
+) -> C::Curve {
+    msm_routed_batch(&[scalars], bases, config, glv)[0]
+}
 
-    if c < 10 {
-        return msm_parallel(coeffs, bases);
+/// Same as [`msm_routed`] for a batch of scalar vectors against the same bases.
+///
+/// The small classes are run per vector, while the full-width scalars of all
+/// vectors share one pass over the bases, unless they are a minority of a single
+/// vector or run through the GLV endomorphism.
+fn msm_routed_batch<C: CurveAffine>(
+    scalars: &[&[C::Scalar]],
+    bases: &[C],
+    config: &MsmConfig,
+    glv: Option<Glv<C>>,
+) -> Vec<C::Curve> {
+    for scalars in scalars.iter() {
+        assert_eq!(scalars.len(), bases.len());
+    }
+    config.assert_valid();
+
+    if let Some(num_threads) = config.num_threads {
+        let pool = thread_pool(num_threads);
+        let config = MsmConfig {
+            num_threads: None,
+            ..*config
+        };
+        return pool.install(|| msm_routed_batch(scalars, bases, &config, glv));
     }
 
     // coeffs to byte representation
-    let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+    let mut coeffs: Vec<Vec<_>> = scalars
+        .par_iter()
+        .map(|scalars| scalars.par_iter().map(|a| a.to_repr()).collect())
+        .collect();
+
+    let num_bits = C::Scalar::NUM_BITS as usize;
+    let mut acc = vec![C::Curve::identity(); scalars.len()];
+    // vectors whose full-width scalars run in the shared pass
+    let mut shared = vec![];
+    for (j, scalars) in scalars.iter().enumerate() {
+        // route every scalar by its width, with the indices of each class contiguous
+        // in `order` as the classes are sorted by width
+        let classes: Vec<_> = coeffs[j]
+            .par_iter()
+            .map(|coeff| ScalarClass::of(coeff.as_ref()))
+            .collect();
+        let mut order: Vec<_> = (0..bases.len()).collect();
+        order.par_sort_by_key(|&i| classes[i]);
+        let num_small = order.partition_point(|&i| classes[i] != ScalarClass::Full);
+        let (small, full) = order.split_at(num_small);
+        // zero scalars contribute nothing
+        let small = &small[small.partition_point(|&i| classes[i] == ScalarClass::Zero)..];
+
+        if !small.is_empty() {
+            // gathered once for all small classes, as they are few in the common case
+            let (small_coeffs, small_bases): (Vec<_>, Vec<_>) = small
+                .par_iter()
+                .map(|&i| {
+                    let num_bytes = classes[i].num_bytes().unwrap();
+                    (&coeffs[j][i].as_ref()[..num_bytes], bases[i])
+                })
+                .unzip();
+
+            let mut start = 0;
+            while start < small.len() {
+                let class = classes[small[start]];
+                let end = start + small[start..].partition_point(|&i| classes[i] == class);
+                let bases = &small_bases[start..end];
+                acc[j] += match class {
+                    ScalarClass::One => bases
+                        .par_iter()
+                        .fold(C::Curve::identity, |acc, base| acc + base)
+                        .reduce(C::Curve::identity, |a, b| a + b),
+                    _ => {
+                        let num_bits = class.num_bytes().unwrap() * 8;
+                        msm_best_repr(&small_coeffs[start..end], bases, num_bits, config)
+                    }
+                };
+                start = end;
+            }
+        }
+
+        if full.is_empty() {
+            continue;
+        }
+        if scalars.len() == 1 && 2 * full.len() <= bases.len() {
+            // full scalars are a minority, so they are gathered to skip the other bases
+            let full_bases: Vec<_> = full.par_iter().map(|&i| bases[i]).collect();
+            acc[j] += match glv {
+                Some(glv) => {
+                    let scalars: Vec<_> = full.par_iter().map(|&i| scalars[i]).collect();
+                    let endo_bases: Option<Vec<_>> = glv
+                        .endo_bases
+                        .map(|endo_bases| full.par_iter().map(|&i| endo_bases[i]).collect());
+                    let glv = Glv {
+                        endo_bases: endo_bases.as_deref(),
+                        ..glv
+                    };
+                    msm_glv(&scalars, &full_bases, None, glv, config)
+                }
+                None => {
+                    let coeffs: Vec<_> = full.par_iter().map(|&i| coeffs[j][i]).collect();
+                    msm_best_repr(&coeffs, &full_bases, num_bits, config)
+                }
+            };
+        } else if let Some(glv) = glv {
+            acc[j] += msm_glv(scalars, bases, Some(&classes), glv, config);
+        } else {
+            // the full class runs over all bases, with the scalars of the other
+            // classes zeroed so that they are skipped
+            coeffs[j]
+                .par_iter_mut()
+                .zip(classes.par_iter())
+                .filter(|(_, class)| **class != ScalarClass::Full)
+                .for_each(|(coeff, _)| *coeff = Default::default());
+            shared.push(j);
+        }
+    }
+
+    if !shared.is_empty() {
+        let coeffs: Vec<_> = shared.iter().map(|&j| &coeffs[j][..]).collect();
+        let results = msm_best_batch_repr(&coeffs, bases, num_bits, config);
+        for (j, result) in shared.into_iter().zip(results) {
+            acc[j] += result;
+        }
+    }
+    acc
+}
+
+/// Width classes [`msm_best`] routes scalars into, so that e.g. selector columns
+/// only pay for additions and byte columns only for 8-bit windows.
+///
+/// Classes are ordered by width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ScalarClass {
+    Zero,
+    One,
+    U8,
+    U16,
+    U32,
+    U64,
+    Full,
+}
+
+impl ScalarClass {
+    fn of(repr: &[u8]) -> Self {
+        let num_bytes = repr.iter().rposition(|v| *v != 0).map_or(0, |i| i + 1);
+        match num_bytes {
+            0 => Self::Zero,
+            1 if repr[0] == 1 => Self::One,
+            1 => Self::U8,
+            2 => Self::U16,
+            3..=4 => Self::U32,
+            5..=8 => Self::U64,
+            _ => Self::Full,
+        }
+    }
+
+    /// Number of low bytes that hold a scalar of this class, `None` if it needs the
+    /// full representation.
+    fn num_bytes(&self) -> Option<usize> {
+        match self {
+            Self::Zero => Some(0),
+            Self::One | Self::U8 => Some(1),
+            Self::U16 => Some(2),
+            Self::U32 => Some(4),
+            Self::U64 => Some(8),
+            Self::Full => None,
+        }
+    }
+}
+
+/// Same as [`msm_best`], over little-endian byte representations of the scalars
+/// that are at most `num_bits` wide.
+fn msm_best_repr<C: CurveAffine, R: AsRef<[u8]> + Sync>(
+    coeffs: &[R],
+    bases: &[C],
+    num_bits: usize,
+    config: &MsmConfig,
+) -> C::Curve {
+    msm_best_batch_repr(&[coeffs], bases, num_bits, config)[0]
+}
+
+/// Performs a batch of multi-scalar multiplication operations against the same bases,
+/// returning one result per coefficient vector.
+///
+/// Scalars are routed by width as in [`msm_best`]. Compared to calling [`msm_best`]
+/// once per coefficient vector, the bases are converted and streamed through memory
+/// once per window for the full-width scalars of the whole batch.
+///
+/// This function will panic if any of the coefficient vectors and bases have a
+/// different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_best_batch<C: CurveAffine>(coeffs: &[&[C::Scalar]], bases: &[C]) -> Vec<C::Curve> {
+    msm_routed_batch(coeffs, bases, &MsmConfig::default(), None)
+}
+
+/// Same as [`msm_best_batch`], over little-endian byte representations of the scalars
+/// that are at most `num_bits` wide.
+fn msm_best_batch_repr<C: CurveAffine, R: AsRef<[u8]> + Sync>(
+    coeffs: &[&[R]],
+    bases: &[C],
+    num_bits: usize,
+    config: &MsmConfig,
+) -> Vec<C::Curve> {
+    for coeffs in coeffs.iter() {
+        assert_eq!(coeffs.len(), bases.len());
+    }
+
+    let c = config.window.unwrap_or_else(|| default_window(bases.len()));
+
+    match config.algorithm(bases.len()) {
+        MsmAlgorithm::Serial => {
+            let mut acc = vec![C::Curve::identity(); coeffs.len()];
+            msm_serial_batch_repr(coeffs, bases, c, &mut acc);
+            return acc;
+        }
+        MsmAlgorithm::Parallel => {
+            return msm_parallel_batch_repr(coeffs, bases, config.window);
+        }
+        MsmAlgorithm::Auto | MsmAlgorithm::BatchAffine => {}
+    }
+
+    // batched affine additions are only defined for short Weierstrass curves,
+    // buckets of twisted Edwards curves stay in extended coordinates
+    let affine_buckets = CurveModel::of::<C>() == CurveModel::ShortWeierstrass;
+
     // copy bases into `Affine` to skip in on curve check for every access
-    let bases_local: Vec<_> = bases.par_iter().map(Affine::from).collect();
+    let bases_local: Vec<_> = if affine_buckets {
+        bases.par_iter().map(Affine::from).collect()
+    } else {
+        vec![]
+    };
 
     // number of windows
-    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
-    // accumumator for each window
-    let mut acc = vec![C::Curve::identity(); number_of_windows];
+    let number_of_windows = num_bits / c + 1;
+    // accumumator for each window and each msm of the batch
+    let mut acc = vec![vec![C::Curve::identity(); coeffs.len()]; number_of_windows];
     acc.par_iter_mut().enumerate().rev().for_each(|(w, acc)| {
         // jacobian buckets for already scheduled points
-        let mut j_bucks = vec![Bucket::<C>::None; 1 << (c - 1)];
+        let mut j_bucks = vec![vec![Bucket::<C>::None; 1 << (c - 1)]; coeffs.len()];
 
         // schedular for affine addition
-        let mut sched = Schedule::new(c);
+        let mut scheds: Vec<_> = (0..coeffs.len())
+            .map(|_| Schedule::new(c, config.batch_size))
+            .collect();
 
-        for (base_idx, coeff) in coeffs.iter().enumerate() {
-            let buck_idx = get_booth_index(w, c, coeff.as_ref());
+        for base_idx in 0..bases.len() {
+            for ((coeffs, j_bucks), sched) in
+                coeffs.iter().zip(j_bucks.iter_mut()).zip(scheds.iter_mut())
+            {
+                let buck_idx = get_booth_index(w, c, coeffs[base_idx].as_ref());
+
+                if buck_idx != 0 {
+                    // parse bucket index
+                    let sign = buck_idx.is_positive();
+                    let buck_idx = buck_idx.unsigned_abs() as usize - 1;
+
+                    if !affine_buckets || sched.contains(buck_idx) {
+                        // greedy accumulation
+                        // we use original bases here
+                        j_bucks[buck_idx].add_assign(&bases[base_idx], sign);
+                    } else {
+                        // also flushes the schedule if full
+                        sched.add(&bases_local, base_idx, buck_idx, sign);
+                    }
+                }
+            }
+        }
 
-            if buck_idx != 0 {
-                // parse bucket index
-                let sign = buck_idx.is_positive();
-                let buck_idx = buck_idx.unsigned_abs() as usize - 1;
+        for ((acc, j_bucks), sched) in acc.iter_mut().zip(j_bucks.iter()).zip(scheds.iter_mut()) {
+            // flush the schedule
+            sched.execute(&bases_local);
+
+            // summation by parts
+            // e.g. 3a + 2b + 1c = a +
+            //                    (a) + b +
+            //                    ((a) + b) + c
+            let mut running_sum = C::Curve::identity();
+            for (j_buck, a_buck) in j_bucks.iter().zip(sched.buckets.iter()).rev() {
+                running_sum += j_buck.add(a_buck);
+                *acc += running_sum;
+            }
 
-                if sched.contains(buck_idx) {
-                    // greedy accumulation
-                    // we use original bases here
-                    j_bucks[buck_idx].add_assign(&bases[base_idx], sign);
-                } else {
-                    // also flushes the schedule if full
-                    sched.add(&bases_local, base_idx, buck_idx, sign);
+            // shift accumulator to the window position
+            for _ in 0..c * w {
+                *acc = acc.double();
+            }
+        }
+    });
+
+    (0..coeffs.len())
+        .map(|i| acc.iter().map(|acc| acc[i]).sum::<_>())
+        .collect()
+}
+
+/// Performs a multi-scalar multiplication operation, using the GLV endomorphism
+/// of the curve to halve the number of windows.
+///
+/// Every scalar `k` is decomposed into two ~128-bit halves `k = k1 - ζ·k2`, and the
+/// MSM is run over `[P_i, endo(P_i)]` with the half-width scalars. Scalars are
+/// otherwise routed by width as in [`msm_best`].
+///
+/// The endomorphism images of the bases are computed on every call, see
+/// [`EndoBases`] to reuse them across MSMs against the same bases.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_best_endo<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve
+where
+    C::CurveExt: CurveEndo,
+{
+    msm_routed(coeffs, bases, &MsmConfig::default(), Some(Glv::new(None)))
+}
+
+/// Bases of multi-scalar multiplications together with their GLV endomorphism images,
+/// see [`msm_best_endo`].
+///
+/// The images cost a batch normalization of all bases, which this computes once for
+/// all MSMs against the same bases.
+#[derive(Debug, Clone)]
+pub struct EndoBases<C: CurveAffine> {
+    bases: Vec<C>,
+    endo_bases: Vec<C>,
+}
+
+impl<C: CurveAffine> EndoBases<C>
+where
+    C::CurveExt: CurveEndo,
+{
+    /// Computes the endomorphism images of `bases`.
+    pub fn new(bases: &[C]) -> Self {
+        Self {
+            bases: bases.to_vec(),
+            endo_bases: endo_images(bases),
+        }
+    }
+
+    /// Returns the number of bases.
+    pub fn len(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Returns `true` if there are no bases.
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty()
+    }
+
+    /// Returns the original bases.
+    pub fn bases(&self) -> &[C] {
+        &self.bases
+    }
+
+    /// Performs [`msm_best_endo`] against the first `coeffs.len()` bases.
+    ///
+    /// This function will panic if there are more coeffs than bases.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn msm(&self, coeffs: &[C::Scalar]) -> C::Curve {
+        assert!(coeffs.len() <= self.len());
+        let n = coeffs.len();
+        let glv = Glv::new(Some(&self.endo_bases[..n]));
+        msm_routed(coeffs, &self.bases[..n], &MsmConfig::default(), Some(glv))
+    }
+}
+
+/// Returns the endomorphism images of `bases`.
+fn endo_images<C: CurveAffine>(bases: &[C]) -> Vec<C>
+where
+    C::CurveExt: CurveEndo,
+{
+    let endo_bases: Vec<_> = bases
+        .par_iter()
+        .map(|base| base.to_curve().endo())
+        .collect();
+    let mut endo_bases_affine = vec![C::identity(); endo_bases.len()];
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((endo_bases.len() + num_threads - 1) / num_threads).max(1);
+    endo_bases
+        .par_chunks(chunk)
+        .zip(endo_bases_affine.par_chunks_mut(chunk))
+        .for_each(|(p, q)| C::Curve::batch_normalize(p, q));
+    endo_bases_affine
+}
+
+/// GLV endomorphism of a curve, as used by [`msm_best_endo`].
+struct Glv<'a, C: CurveAffine> {
+    /// [`CurveEndo::decompose_scalar`] of the curve.
+    decompose: fn(&C::Scalar) -> (u128, bool, u128, bool),
+    /// [`endo_images`] of the curve.
+    endo_images: fn(&[C]) -> Vec<C>,
+    /// Endomorphism images of the bases, computed on demand if `None`.
+    endo_bases: Option<&'a [C]>,
+}
+
+// not derived, which would require `C: Copy` rather than `C::Scalar: Copy`
+impl<C: CurveAffine> Clone for Glv<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: CurveAffine> Copy for Glv<'_, C> {}
+
+impl<'a, C: CurveAffine> Glv<'a, C>
+where
+    C::CurveExt: CurveEndo,
+{
+    fn new(endo_bases: Option<&'a [C]>) -> Self {
+        Self {
+            decompose: C::CurveExt::decompose_scalar,
+            endo_images: endo_images::<C>,
+            endo_bases,
+        }
+    }
+}
+
+/// Runs [`msm_best_endo`] over all scalars, or only over those of
+/// [`ScalarClass::Full`] if `classes` is given.
+fn msm_glv<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    classes: Option<&[ScalarClass]>,
+    glv: Glv<C>,
+    config: &MsmConfig,
+) -> C::Curve {
+    let endo_bases = match glv.endo_bases {
+        Some(endo_bases) => std::borrow::Cow::Borrowed(endo_bases),
+        None => std::borrow::Cow::Owned((glv.endo_images)(bases)),
+    };
+
+    let (coeffs, bases): (Vec<_>, Vec<_>) = coeffs
+        .par_iter()
+        .zip(bases.par_iter().zip(endo_bases.par_iter()))
+        .enumerate()
+        .flat_map_iter(|(i, (coeff, (base, endo)))| {
+            let (k1, k1_neg, k2, k2_neg) = match classes {
+                Some(classes) if classes[i] != ScalarClass::Full => (0, false, 0, false),
+                _ => (glv.decompose)(coeff),
+            };
+            // k = ±k1 - ζ·(±k2), so k·P = (±k1)·P + (∓k2)·endo(P)
+            let base = if k1_neg { -*base } else { *base };
+            let endo = if k2_neg { *endo } else { -*endo };
+            [(k1.to_le_bytes(), base), (k2.to_le_bytes(), endo)]
+        })
+        .unzip();
+
+    msm_best_repr(&coeffs, &bases, 128, config)
+}
+
+/// A claim that `Σ coeffs[i]·bases[i] == result`.
+#[derive(Clone, Copy, Debug)]
+pub struct MsmClaim<'a, C: CurveAffine> {
+    pub coeffs: &'a [C::Scalar],
+    pub bases: &'a [C],
+    pub result: C,
+}
+
+/// Checks all claims at once with a single MSM over a random linear
+/// combination of them.
+fn batch_check_msm<C: CurveAffine>(claims: &[MsmClaim<C>], mut rng: impl RngCore) -> bool {
+    let len = claims.iter().map(|claim| claim.coeffs.len() + 1).sum();
+    let mut coeffs = Vec::with_capacity(len);
+    let mut bases = Vec::with_capacity(len);
+    for claim in claims {
+        let r = C::Scalar::random(&mut rng);
+        // identity points contribute nothing, and have no affine coordinates for the
+        // batched additions of `msm_best` to work on
+        for (coeff, base) in claim.coeffs.iter().zip(claim.bases.iter()) {
+            if !bool::from(base.is_identity()) {
+                coeffs.push(*coeff * r);
+                bases.push(*base);
+            }
+        }
+        if !bool::from(claim.result.is_identity()) {
+            coeffs.push(-r);
+            bases.push(claim.result);
+        }
+    }
+    bool::from(msm_best(&coeffs, &bases).is_identity())
+}
+
+/// Verifies a set of MSM claims with random linear combination challenges and
+/// one [`msm_best`] call.
+///
+/// If the batch check fails, the claims are bisected to locate a failing one,
+/// whose index is returned as the error. A false claim passes with probability
+/// at most `1/|F|` per check.
+///
+/// This function will panic if the coeffs and bases of a claim have a different
+/// length.
+pub fn batch_verify_msm<C: CurveAffine>(
+    claims: &[MsmClaim<C>],
+    mut rng: impl RngCore,
+) -> Result<(), usize> {
+    for claim in claims {
+        assert_eq!(claim.coeffs.len(), claim.bases.len());
+    }
+
+    if batch_check_msm(claims, &mut rng) {
+        return Ok(());
+    }
+
+    // The batch of `claims[offset..offset + len]` is known to fail. If its left
+    // half passes, the right half must be the one that fails.
+    let (mut offset, mut len) = (0, claims.len());
+    while len > 1 {
+        let mid = len / 2;
+        if batch_check_msm(&claims[offset..offset + mid], &mut rng) {
+            offset += mid;
+            len -= mid;
+        } else {
+            len = mid;
+        }
+    }
+    Err(offset)
+}
+
+/// A prime-order subgroup of the cyclotomic subgroup of an extension field, such as
+/// the target group `Gt` of a pairing, written additively.
+///
+/// Elements are unitary, so negation is a conjugation, and doubling can use the
+/// faster squaring formula of the cyclotomic subgroup.
+pub trait CyclotomicGroup: Group {
+    /// Doubles this element with a cyclotomic squaring.
+    #[must_use]
+    fn cyclotomic_double(&self) -> Self;
+}
+
+/// Performs a multi-exponentiation in a cyclotomic group such as `Gt`, using the
+/// Pippenger algorithm with Booth-encoded windows.
+///
+/// Buckets are accumulated per window in parallel. Negative digits only cost a
+/// conjugation, and windows are combined with cyclotomic squarings.
+///
+/// This function will panic if coeffs and bases have a different length.
+pub fn multiexp_cyclotomic<G: CyclotomicGroup>(coeffs: &[G::Scalar], bases: &[G]) -> G {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+    let c = default_window(bases.len());
+    let field_byte_size = coeffs.first().map_or(0, |coeff| coeff.as_ref().len());
+    let number_of_windows = field_byte_size * 8 / c + 1;
+
+    let window_sums: Vec<G> = (0..number_of_windows)
+        .into_par_iter()
+        .map(|w| {
+            // Empty buckets are tracked so that no multiplication by one is spent.
+            let mut buckets: Vec<Option<G>> = vec![None; 1 << (c - 1)];
+            for (coeff, base) in coeffs.iter().zip(bases.iter()) {
+                let idx = get_booth_index(w, c, coeff.as_ref());
+                if idx == 0 {
+                    continue;
+                }
+                let base = if idx.is_negative() { -*base } else { *base };
+                let bucket = &mut buckets[idx.unsigned_abs() as usize - 1];
+                *bucket = Some(match bucket {
+                    Some(acc) => *acc + base,
+                    None => base,
+                });
+            }
+
+            // Summation by parts
+            let mut running_sum: Option<G> = None;
+            let mut acc: Option<G> = None;
+            for bucket in buckets.into_iter().rev() {
+                running_sum = match (running_sum, bucket) {
+                    (Some(sum), Some(bucket)) => Some(sum + bucket),
+                    (sum, bucket) => sum.or(bucket),
+                };
+                if let Some(sum) = running_sum {
+                    acc = Some(acc.map_or(sum, |acc| acc + sum));
+                }
+            }
+            acc.unwrap_or_else(G::identity)
+        })
+        .collect();
+
+    window_sums
+        .iter()
+        .rev()
+        .fold(G::identity(), |mut acc, sum| {
+            for _ in 0..c {
+                acc = acc.cyclotomic_double();
+            }
+            acc + sum
+        })
+}
+
+/// Window size of [`msm_constant_time`].
+const CT_WINDOW: usize = 4;
+
+/// Recodes a little-endian scalar representation into signed digits in
+/// `[-2^(CT_WINDOW-1), 2^(CT_WINDOW-1)]`, without branching on the scalar.
+///
+/// The number of digits only depends on the length of `repr`.
+fn ct_signed_digits(repr: &[u8]) -> Vec<i8> {
+    let mut digits = Vec::with_capacity(repr.len() * 2 + 1);
+    for byte in repr.iter() {
+        digits.push((byte & 0xf) as i8);
+        digits.push((byte >> 4) as i8);
+    }
+    digits.push(0);
+
+    // shift every digit from [0, 16) to [-8, 8) and carry into the next one
+    for i in 0..digits.len() - 1 {
+        let carry = (digits[i] + 8) >> CT_WINDOW;
+        digits[i] -= carry << CT_WINDOW;
+        digits[i + 1] += carry;
+    }
+    digits
+}
+
+/// Performs a constant-time multi-scalar multiplication operation, for secret scalars
+/// such as blinding factors.
+///
+/// The sequence of group operations and memory accesses only depends on the number of
+/// points and the size of the scalar field: every scalar is recoded into the same number
+/// of signed 4-bit digits, and digits are looked up from per-base tables of multiples
+/// with [`ConditionallySelectable`](subtle::ConditionallySelectable) scans, so zero
+/// digits and zero scalars are processed like any other. The bases are treated as public.
+///
+/// This is considerably slower than [`msm_best`] and should only be used when scalars
+/// must not leak through timing.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_constant_time<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+    if coeffs.is_empty() {
+        return C::Curve::identity();
+    }
+
+    const TABLE_SIZE: usize = 1 << (CT_WINDOW - 1);
+
+    let num_threads = rayon::current_num_threads();
+    let chunk = (coeffs.len() + num_threads - 1) / num_threads;
+
+    coeffs
+        .par_chunks(chunk)
+        .zip(bases.par_chunks(chunk))
+        .map(|(coeffs, bases)| {
+            let digits: Vec<_> = coeffs
+                .iter()
+                .map(|coeff| ct_signed_digits(coeff.to_repr().as_ref()))
+                .collect();
+
+            // `tables[i * TABLE_SIZE + j]` holds `(j + 1) * bases[i]`
+            let mut tables = vec![C::Curve::identity(); bases.len() * TABLE_SIZE];
+            for (table, base) in tables.chunks_mut(TABLE_SIZE).zip(bases.iter()) {
+                table[0] = base.to_curve();
+                for j in 1..TABLE_SIZE {
+                    table[j] = table[j - 1] + base;
+                }
+            }
+            let mut tables_affine = vec![C::identity(); tables.len()];
+            C::Curve::batch_normalize(&tables, &mut tables_affine);
+
+            let num_digits = digits[0].len();
+            let mut acc = C::Curve::identity();
+            for d in (0..num_digits).rev() {
+                for _ in 0..CT_WINDOW {
+                    acc = acc.double();
+                }
+
+                for (digits, table) in digits.iter().zip(tables_affine.chunks(TABLE_SIZE)) {
+                    let digit = digits[d];
+                    let is_neg = (digit >> 7) & 1;
+                    let abs = ((digit ^ -is_neg) + is_neg) as u8;
+
+                    // scan the whole table to select `|digit| * base`
+                    let mut point = C::identity();
+                    for (j, entry) in table.iter().enumerate() {
+                        point = C::conditional_select(&point, entry, abs.ct_eq(&(j as u8 + 1)));
+                    }
+                    let point = C::conditional_select(&point, &-point, Choice::from(is_neg as u8));
+                    acc += point;
                 }
             }
+            acc
+        })
+        .reduce(C::Curve::identity, |a, b| a + b)
+}
+
+/// Precomputed tables for multi-scalar multiplications against a fixed set of bases.
+///
+/// For every base `P_i` and every Booth window `w` the table stores `2^(c * w) * P_i`,
+/// where `c` is the window size. An MSM against these bases then reduces to a single
+/// bucket accumulation pass over all windows at once, using only additions.
+#[derive(Debug, Clone)]
+pub struct FixedBaseMsm<C: CurveAffine> {
+    window: usize,
+    num_bases: usize,
+    // window-major: the shifted base `2^(c * w) * P_i` lives at `w * num_bases + i`
+    table: Vec<C>,
+}
+
+impl<C: CurveAffine> FixedBaseMsm<C> {
+    /// Precomputes the table for `bases` with a window size picked from the number of bases.
+    pub fn new(bases: &[C]) -> Self {
+        let window = if bases.len() < 32 {
+            3
+        } else {
+            (bases.len().ilog2() as usize).min(16)
+        };
+        Self::new_with_window(bases, window)
+    }
+
+    /// Precomputes the table for `bases` with the given window size.
+    ///
+    /// The table holds `(NUM_BITS / window + 1) * bases.len()` points, so larger windows
+    /// trade bucket reduction time for memory.
+    ///
+    /// This function will panic if `window` is not in `1..=24`.
+    pub fn new_with_window(bases: &[C], window: usize) -> Self {
+        assert!((1..=24).contains(&window));
+        let num_bases = bases.len();
+        let number_of_windows = C::Scalar::NUM_BITS as usize / window + 1;
+
+        let mut projective = vec![C::Curve::identity(); number_of_windows * num_bases];
+        if num_bases != 0 {
+            projective[..num_bases]
+                .par_iter_mut()
+                .zip(bases.par_iter())
+                .for_each(|(p, base)| *p = base.to_curve());
+            for w in 1..number_of_windows {
+                let (prev, cur) = projective.split_at_mut(w * num_bases);
+                let prev = &prev[(w - 1) * num_bases..];
+                cur[..num_bases]
+                    .par_iter_mut()
+                    .zip(prev.par_iter())
+                    .for_each(|(p, prev)| {
+                        *p = *prev;
+                        for _ in 0..window {
+                            *p = p.double();
+                        }
+                    });
+            }
         }
 
-        // flush the schedule
-        sched.execute(&bases_local);
+        let mut table = vec![C::identity(); projective.len()];
+        let num_threads = rayon::current_num_threads();
+        let chunk = ((projective.len() + num_threads - 1) / num_threads).max(1);
+        projective
+            .par_chunks(chunk)
+            .zip(table.par_chunks_mut(chunk))
+            .for_each(|(p, q)| C::Curve::batch_normalize(p, q));
 
-        // summation by parts
-        // e.g. 3a + 2b + 1c = a +
-        //                    (a) + b +
-        //                    ((a) + b) + c
-        let mut running_sum = C::Curve::identity();
-        for (j_buck, a_buck) in j_bucks.iter().zip(sched.buckets.iter()).rev() {
-            running_sum += j_buck.add(a_buck);
-            *acc += running_sum;
+        Self {
+            window,
+            num_bases,
+            table,
         }
+    }
+
+    /// Returns the window size the table was built with.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the number of bases covered by the table.
+    pub fn len(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns `true` if the table covers no bases.
+    pub fn is_empty(&self) -> bool {
+        self.num_bases == 0
+    }
 
-        // shift accumulator to the window position
-        for _ in 0..c * w {
-            *acc = acc.double();
+    /// Returns the original bases the table was built from.
+    pub fn bases(&self) -> &[C] {
+        &self.table[..self.num_bases]
+    }
+
+    fn number_of_windows(&self) -> usize {
+        C::Scalar::NUM_BITS as usize / self.window + 1
+    }
+
+    /// Performs a multi-scalar multiplication against the first `coeffs.len()` bases.
+    ///
+    /// This function will panic if there are more coeffs than bases in the table.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn msm(&self, coeffs: &[C::Scalar]) -> C::Curve {
+        assert!(coeffs.len() <= self.num_bases);
+        if coeffs.is_empty() {
+            return C::Curve::identity();
         }
-    });
-    acc.into_iter().sum::<_>()
+
+        let c = self.window;
+        let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+        let num_threads = rayon::current_num_threads();
+        let chunk = (coeffs.len() + num_threads - 1) / num_threads;
+
+        coeffs
+            .par_chunks(chunk)
+            .enumerate()
+            .map(|(chunk_idx, coeffs)| {
+                let offset = chunk_idx * chunk;
+                let mut buckets = vec![Bucket::<C>::None; 1 << (c - 1)];
+
+                for w in 0..self.number_of_windows() {
+                    let bases = &self.table[w * self.num_bases + offset..];
+                    for (coeff, base) in coeffs.iter().zip(bases.iter()) {
+                        let buck_idx = get_booth_index(w, c, coeff.as_ref());
+                        if buck_idx != 0 {
+                            buckets[buck_idx.unsigned_abs() as usize - 1]
+                                .add_assign(base, buck_idx.is_positive());
+                        }
+                    }
+                }
+
+                // summation by parts
+                // e.g. 3a + 2b + 1c = a +
+                //                    (a) + b +
+                //                    ((a) + b) + c
+                let mut acc = C::Curve::identity();
+                let mut running_sum = C::Curve::identity();
+                for bucket in buckets.iter().rev() {
+                    running_sum += bucket.eval();
+                    acc += running_sum;
+                }
+                acc
+            })
+            .reduce(C::Curve::identity, |a, b| a + b)
+    }
+}
+
+impl<C: CurveAffine + SerdeObject> SerdeObject for FixedBaseMsm<C> {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        Self::read_raw_unchecked(&mut &bytes[..])
+    }
+
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = bytes;
+        let res = Self::read_raw(&mut reader).ok()?;
+        reader.is_empty().then_some(res)
+    }
+
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::new();
+        self.write_raw(&mut res).unwrap();
+        res
+    }
+
+    fn read_raw_unchecked<R: Read>(reader: &mut R) -> Self {
+        let mut window = [0u8; 4];
+        let mut num_bases = [0u8; 8];
+        reader.read_exact(&mut window).unwrap();
+        reader.read_exact(&mut num_bases).unwrap();
+        let window = u32::from_le_bytes(window) as usize;
+        let num_bases = u64::from_le_bytes(num_bases) as usize;
+
+        let len = (C::Scalar::NUM_BITS as usize / window + 1) * num_bases;
+        let table = (0..len).map(|_| C::read_raw_unchecked(reader)).collect();
+        Self {
+            window,
+            num_bases,
+            table,
+        }
+    }
+
+    fn read_raw<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut window = [0u8; 4];
+        let mut num_bases = [0u8; 8];
+        reader.read_exact(&mut window)?;
+        reader.read_exact(&mut num_bases)?;
+        let window = u32::from_le_bytes(window) as usize;
+        let num_bases = u64::from_le_bytes(num_bases) as usize;
+        if !(1..=24).contains(&window) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid window size",
+            ));
+        }
+
+        let len = (C::Scalar::NUM_BITS as usize / window + 1) * num_bases;
+        let table = (0..len)
+            .map(|_| C::read_raw(reader))
+            .collect::<io::Result<_>>()?;
+        Ok(Self {
+            window,
+            num_bases,
+            table,
+        })
+    }
+
+    fn write_raw<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&(self.window as u32).to_le_bytes())?;
+        writer.write_all(&(self.num_bases as u64).to_le_bytes())?;
+        for point in self.table.iter() {
+            point.write_raw(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Performs a multi-scalar multiplication operation, using the precomputed `table`
+/// for `bases` if one is given and it covers all coeffs.
+///
+/// Falls back to [`msm_best`] otherwise.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_fixed_base<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    table: Option<&FixedBaseMsm<C>>,
+) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    match table {
+        Some(table) if table.len() >= coeffs.len() => {
+            debug_assert!(table.bases()[..bases.len()] == *bases);
+            table.msm(coeffs)
+        }
+        _ => msm_best(coeffs, bases),
+    }
+}
+
+/// Performs a multi-scalar multiplication operation against bases streamed from
+/// `reader`, without materializing all of them in memory.
+///
+/// The bases are expected in their raw [`SerdeObject`] encoding, one after another,
+/// e.g. as written by [`SerdeObject::write_raw`]. They are read `chunk_size` points
+/// at a time, and the next chunk is read while the MSM over the current one runs.
+/// Memory-mapped files can be streamed by passing the mapped bytes as the reader.
+///
+/// As with [`SerdeObject::read_raw_unchecked`], no checks are performed on the
+/// decoded points, so this should only be used on trusted data such as a locally
+/// stored SRS.
+///
+/// This function will panic if `chunk_size` is zero, and returns an error if the
+/// reader holds fewer bases than coeffs.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_best_streaming<C, R>(
+    coeffs: &[C::Scalar],
+    reader: &mut R,
+    chunk_size: usize,
+) -> io::Result<C::Curve>
+where
+    C: CurveAffine + SerdeObject,
+    R: Read + Send,
+{
+    assert!(chunk_size > 0);
+
+    let point_size = C::identity().to_raw_bytes().len();
+    let read_chunk = |reader: &mut R, len: usize| -> io::Result<Vec<u8>> {
+        let mut buf = vec![0u8; len * point_size];
+        reader.read_exact(&mut buf)?;
+        Ok(buf)
+    };
+
+    let mut acc = C::Curve::identity();
+    let mut chunks = coeffs.chunks(chunk_size);
+    let Some(mut coeffs) = chunks.next() else {
+        return Ok(acc);
+    };
+    let mut buf = read_chunk(reader, coeffs.len())?;
+    loop {
+        let next = chunks.next();
+        let (partial, next_buf) = rayon::join(
+            || {
+                let bases: Vec<C> = buf
+                    .par_chunks(point_size)
+                    .map(C::from_raw_bytes_unchecked)
+                    .collect();
+                msm_best(coeffs, &bases)
+            },
+            || next.map(|next| read_chunk(reader, next.len())).transpose(),
+        );
+        acc += partial;
+
+        match (next, next_buf?) {
+            (Some(next), Some(next_buf)) => {
+                coeffs = next;
+                buf = next_buf;
+            }
+            _ => return Ok(acc),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -539,9 +1905,9 @@ mod test {
     use crate::bn256::{Fr, G1Affine, G1};
     use ark_std::{end_timer, start_timer};
     use ff::{Field, PrimeField};
-    use group::{Curve, Group};
+    use group::{prime::PrimeCurveAffine, Curve, Group};
     use pasta_curves::arithmetic::CurveAffine;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
 
     #[test]
     fn test_booth_encoding() {
@@ -624,4 +1990,489 @@ mod test {
     fn test_msm_cross() {
         run_msm_cross::<G1Affine>(14, 22);
     }
+
+    #[test]
+    fn test_msm_with_config() {
+        use super::{MsmAlgorithm, MsmConfig};
+
+        let k = 12;
+        let points = (0..1 << k).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::identity(); 1 << k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..1 << k).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        for n in [3, 100, 1 << k] {
+            let expected = super::msm_parallel(&scalars[..n], &bases[..n]);
+            for algorithm in MsmAlgorithm::ALL {
+                for window in [None, Some(1), Some(4), Some(11)] {
+                    for (batch_size, num_threads) in [(64, None), (7, Some(1)), (200, Some(3))] {
+                        let config = MsmConfig {
+                            window,
+                            batch_size,
+                            num_threads,
+                            algorithm,
+                        };
+                        let e = super::msm_with_config(&scalars[..n], &bases[..n], &config);
+                        assert_eq!(e, expected, "{:?}", config);
+                    }
+                }
+            }
+        }
+
+        // pools are built once per thread count
+        let pool = super::thread_pool(3);
+        assert_eq!(pool.current_num_threads(), 3);
+        assert!(std::sync::Arc::ptr_eq(&pool, &super::thread_pool(3)));
+    }
+
+    #[test]
+    fn test_msm_profile() {
+        use super::MsmProfile;
+
+        let profile = MsmProfile::calibrate::<G1Affine>(4..=6);
+        assert_eq!(profile.curve_id(), "bn256_g1");
+
+        let mut bytes = vec![];
+        profile.write(&mut bytes).unwrap();
+        assert_eq!(MsmProfile::read(&mut &bytes[..]).unwrap(), profile);
+        assert!(MsmProfile::read(&mut &b"curve bn256_g1\n4 serial 0 64 auto\n"[..]).is_err());
+        assert!(MsmProfile::read(&mut &b"4 serial 3 64 auto\n"[..]).is_err());
+
+        let config = profile.config(1 << 5);
+        assert_eq!(config, profile.config((1 << 6) - 1));
+        assert_eq!(profile.config(1), profile.config(1 << 4));
+        assert_eq!(profile.config(1 << 20), profile.config(1 << 6));
+
+        let path = std::env::temp_dir().join(format!("msm_profile_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let calibrated = MsmProfile::load_or_calibrate::<G1Affine>(&path, 4..=5).unwrap();
+        let loaded = MsmProfile::load_or_calibrate::<G1Affine>(&path, 10..=20).unwrap();
+        assert_eq!(calibrated, loaded);
+        let err = MsmProfile::load_or_calibrate::<crate::bn256::G2Affine>(&path, 4..=5);
+        assert_eq!(err.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(MsmProfile::load(&path).unwrap(), calibrated);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_curve_model() {
+        use super::CurveModel;
+
+        assert_eq!(CurveModel::of::<G1Affine>(), CurveModel::ShortWeierstrass);
+        assert_eq!(
+            CurveModel::of::<crate::bn256::G2Affine>(),
+            CurveModel::ShortWeierstrass
+        );
+        assert_eq!(
+            CurveModel::of::<crate::bls12_381::G1Affine>(),
+            CurveModel::ShortWeierstrass
+        );
+        assert_eq!(
+            CurveModel::of::<crate::pasta::EpAffine>(),
+            CurveModel::ShortWeierstrass
+        );
+        assert_eq!(
+            CurveModel::of::<crate::ed25519::Ed25519Affine>(),
+            CurveModel::TwistedEdwards
+        );
+    }
+
+    #[test]
+    fn test_msm_ed25519() {
+        use super::{MsmAlgorithm, MsmConfig};
+        use crate::ed25519::{Ed25519, Ed25519Affine, Fr};
+
+        let k = 13;
+        let points = (0..1 << k)
+            .map(|_| Ed25519::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut bases = vec![Ed25519Affine::identity(); 1 << k];
+        Ed25519::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..1 << k).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        let expected = scalars
+            .iter()
+            .zip(bases.iter())
+            .fold(Ed25519::identity(), |acc, (scalar, base)| {
+                acc + base * scalar
+            });
+        assert_eq!(super::msm_parallel(&scalars, &bases), expected);
+        assert_eq!(super::msm_best(&scalars, &bases), expected);
+        let config = MsmConfig {
+            window: Some(4),
+            algorithm: MsmAlgorithm::BatchAffine,
+            ..MsmConfig::default()
+        };
+        assert_eq!(super::msm_with_config(&scalars, &bases, &config), expected);
+        assert_eq!(
+            super::msm_best_batch(&[&scalars[..], &scalars[..]], &bases),
+            vec![expected; 2]
+        );
+    }
+
+    #[test]
+    fn test_msm_small_scalars() {
+        let k = 13;
+        let points = (0..1 << k).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::identity(); 1 << k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+
+        let mut rng = OsRng;
+        let small = |rng: &mut OsRng, bits: u32| Fr::from(rng.next_u64() >> (64 - bits));
+        let columns = [
+            (0..1 << k).map(|_| Fr::ZERO).collect::<Vec<_>>(),
+            (0..1 << k).map(|i| Fr::from(i % 2)).collect::<Vec<_>>(),
+            (0..1 << k).map(|_| small(&mut rng, 8)).collect::<Vec<_>>(),
+            (0..1 << k)
+                .map(|i| match i % 8 {
+                    0 => Fr::ZERO,
+                    1 => Fr::ONE,
+                    2 => small(&mut rng, 8),
+                    3 => small(&mut rng, 16),
+                    4 => small(&mut rng, 32),
+                    5 => small(&mut rng, 64),
+                    6 => -Fr::ONE,
+                    _ => Fr::random(&mut rng),
+                })
+                .collect::<Vec<_>>(),
+            // full-width but for a single zero
+            (0..1 << k)
+                .map(|i| {
+                    if i == 5 {
+                        Fr::ZERO
+                    } else {
+                        Fr::random(&mut rng)
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ];
+
+        let endo_bases = super::EndoBases::new(&bases);
+        for scalars in columns.iter() {
+            for n in [3, 20, 1 << k] {
+                let e0 = super::msm_parallel(&scalars[..n], &bases[..n]);
+                let e1 = super::msm_best(&scalars[..n], &bases[..n]);
+                let e2 = super::msm_with_config(&scalars[..n], &bases[..n], &Default::default());
+                let e3 = super::msm_best_endo(&scalars[..n], &bases[..n]);
+                let e4 = endo_bases.msm(&scalars[..n]);
+                assert_eq!(e0, e1);
+                assert_eq!(e0, e2);
+                assert_eq!(e0, e3);
+                assert_eq!(e0, e4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_batch() {
+        let k = 13;
+        let points = (0..1 << k).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::identity(); 1 << k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        let mut rng = OsRng;
+        let scalars = (0..4)
+            .map(|j| {
+                (0..1 << k)
+                    .map(|i| match (j + i) % 4 {
+                        // batches mix vectors of different widths
+                        _ if j == 3 => Fr::from(rng.next_u64() >> 48),
+                        0 => Fr::ZERO,
+                        1 => Fr::ONE,
+                        _ => Fr::random(&mut rng),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for n in [3, 20, 1000, 1 << k] {
+            let coeffs = scalars.iter().map(|s| &s[..n]).collect::<Vec<_>>();
+            let e0 = coeffs
+                .iter()
+                .map(|coeffs| super::msm_parallel(coeffs, &bases[..n]))
+                .collect::<Vec<_>>();
+            let e1 = super::msm_best_batch(&coeffs, &bases[..n]);
+            assert_eq!(e0, e1);
+        }
+        assert!(super::msm_best_batch::<G1Affine>(&[], &bases).is_empty());
+    }
+
+    fn run_msm_endo<C: CurveAffine>(k: usize)
+    where
+        C::CurveExt: crate::CurveEndo,
+    {
+        let points = (0..1 << k)
+            .map(|_| C::Curve::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut bases = vec![C::identity(); 1 << k];
+        C::Curve::batch_normalize(&points[..], &mut bases[..]);
+        let mut scalars = (0..1 << k)
+            .map(|_| C::Scalar::random(OsRng))
+            .collect::<Vec<_>>();
+        scalars[0] = C::Scalar::ZERO;
+        scalars[1] = C::Scalar::ONE;
+        scalars[2] = -C::Scalar::ONE;
+
+        let endo_bases = super::EndoBases::new(&bases);
+        for n in [1, 3, 20, 1 << k] {
+            let e0 = super::msm_best(&scalars[..n], &bases[..n]);
+            let e1 = super::msm_best_endo(&scalars[..n], &bases[..n]);
+            let e2 = endo_bases.msm(&scalars[..n]);
+            assert_eq!(e0, e1);
+            assert_eq!(e0, e2);
+        }
+    }
+
+    #[test]
+    fn test_msm_endo() {
+        run_msm_endo::<G1Affine>(13);
+        run_msm_endo::<crate::grumpkin::G1Affine>(10);
+        run_msm_endo::<crate::bls12_381::G1Affine>(10);
+        run_msm_endo::<crate::pasta::EpAffine>(10);
+        run_msm_endo::<crate::pasta::EqAffine>(10);
+    }
+
+    #[test]
+    fn test_msm_streaming() {
+        use crate::serde::SerdeObject;
+
+        let k = 10;
+        let points = (0..1 << k).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::identity(); 1 << k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..1 << k).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        let mut bytes = vec![];
+        for base in bases.iter() {
+            base.write_raw(&mut bytes).unwrap();
+        }
+
+        for n in [0, 1, 100, 1 << k] {
+            let expected = super::msm_best(&scalars[..n], &bases[..n]);
+            for chunk_size in [1, 7, 256, 1 << (k + 1)] {
+                let e = super::msm_best_streaming::<G1Affine, _>(
+                    &scalars[..n],
+                    &mut &bytes[..],
+                    chunk_size,
+                )
+                .unwrap();
+                assert_eq!(e, expected);
+            }
+        }
+
+        let mut reader = std::io::Cursor::new(&bytes[..bytes.len() - 1]);
+        assert!(super::msm_best_streaming::<G1Affine, _>(&scalars, &mut reader, 100).is_err());
+    }
+
+    #[test]
+    fn test_ct_signed_digits() {
+        for _ in 0..100 {
+            let scalar = Fr::random(OsRng);
+            let digits = super::ct_signed_digits(scalar.to_repr().as_ref());
+            assert_eq!(digits.len(), 65);
+            let mut acc = Fr::ZERO;
+            for digit in digits.iter().rev() {
+                assert!((-8..=8).contains(digit));
+                acc *= Fr::from(16);
+                if digit.is_negative() {
+                    acc -= Fr::from(digit.unsigned_abs() as u64);
+                } else {
+                    acc += Fr::from(*digit as u64);
+                }
+            }
+            assert_eq!(acc, scalar);
+        }
+    }
+
+    fn run_msm_constant_time<C: CurveAffine>(k: usize) {
+        let points = (0..1 << k)
+            .map(|_| C::Curve::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut bases = vec![C::identity(); 1 << k];
+        C::Curve::batch_normalize(&points[..], &mut bases[..]);
+        let mut scalars = (0..1 << k)
+            .map(|_| C::Scalar::random(OsRng))
+            .collect::<Vec<_>>();
+        scalars[0] = C::Scalar::ZERO;
+        scalars[1] = C::Scalar::ONE;
+        scalars[2] = -C::Scalar::ONE;
+        scalars[3] = C::Scalar::from(8);
+
+        for n in [0, 1, 4, 20, 1 << k] {
+            let e0 = super::msm_best(&scalars[..n], &bases[..n]);
+            let e1 = super::msm_constant_time(&scalars[..n], &bases[..n]);
+            assert_eq!(e0, e1);
+        }
+    }
+
+    #[test]
+    fn test_msm_constant_time() {
+        run_msm_constant_time::<G1Affine>(8);
+        run_msm_constant_time::<crate::bls12_381::G1Affine>(6);
+        run_msm_constant_time::<crate::secp256k1::Secp256k1Affine>(6);
+        run_msm_constant_time::<crate::pluto_eris::G1Affine>(6);
+    }
+
+    #[test]
+    fn test_fixed_base_msm() {
+        use crate::serde::SerdeObject;
+
+        let k = 10;
+        let points = (0..1 << k).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::identity(); 1 << k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..1 << k).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        for window in [1, 4, 9] {
+            let table = super::FixedBaseMsm::new_with_window(&bases, window);
+            for n in [0, 1, 3, 100, 1 << k] {
+                let e0 = super::msm_best(&scalars[..n], &bases[..n]);
+                let e1 = table.msm(&scalars[..n]);
+                assert_eq!(e0, e1);
+            }
+        }
+
+        let table = super::FixedBaseMsm::new(&bases);
+        let bytes = table.to_raw_bytes();
+        let table = super::FixedBaseMsm::<G1Affine>::from_raw_bytes(&bytes).unwrap();
+        assert_eq!(
+            super::msm_best(&scalars, &bases),
+            super::msm_fixed_base(&scalars, &bases, Some(&table))
+        );
+        let table = super::FixedBaseMsm::<G1Affine>::from_raw_bytes_unchecked(&bytes);
+        assert_eq!(
+            super::msm_best(&scalars, &bases),
+            super::msm_fixed_base(&scalars, &bases, Some(&table))
+        );
+        assert!(
+            super::FixedBaseMsm::<G1Affine>::from_raw_bytes(&bytes[..bytes.len() - 1]).is_none()
+        );
+    }
+
+    #[test]
+    fn test_batch_verify_msm() {
+        let mut rng = OsRng;
+        let claims: Vec<(Vec<Fr>, Vec<G1Affine>, G1Affine)> = (0..13)
+            .map(|i| {
+                let coeffs: Vec<_> = (0..i + 1).map(|_| Fr::random(&mut rng)).collect();
+                let bases: Vec<_> = (0..i + 1)
+                    .map(|_| G1::random(&mut rng).to_affine())
+                    .collect();
+                let result = super::msm_best(&coeffs, &bases).to_affine();
+                (coeffs, bases, result)
+            })
+            .collect();
+        let to_claims = |results: &[G1Affine]| -> Vec<super::MsmClaim<G1Affine>> {
+            claims
+                .iter()
+                .zip(results.iter())
+                .map(|((coeffs, bases, _), result)| super::MsmClaim {
+                    coeffs,
+                    bases,
+                    result: *result,
+                })
+                .collect()
+        };
+
+        let results: Vec<_> = claims.iter().map(|claim| claim.2).collect();
+        assert_eq!(
+            super::batch_verify_msm(&to_claims(&results), &mut rng),
+            Ok(())
+        );
+        assert_eq!(super::batch_verify_msm::<G1Affine>(&[], &mut rng), Ok(()));
+
+        for bad in 0..claims.len() {
+            let mut results = results.clone();
+            results[bad] = (results[bad] + G1Affine::generator()).to_affine();
+            assert_eq!(
+                super::batch_verify_msm(&to_claims(&results), &mut rng),
+                Err(bad)
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_verify_msm_identity() {
+        use super::MsmClaim;
+
+        // large enough for `msm_best` to take batched affine additions
+        let n = 1 << 13;
+        let mut rng = OsRng;
+        let mut bases: Vec<_> = (0..n).map(|_| G1::random(&mut rng).to_affine()).collect();
+        bases[1] = G1Affine::identity();
+        let zeros = vec![Fr::ZERO; n];
+        let coeffs: Vec<_> = (0..n).map(|_| Fr::random(&mut rng)).collect();
+        let result = super::msm_parallel(&coeffs, &bases).to_affine();
+
+        let claims = [
+            MsmClaim {
+                coeffs: &zeros,
+                bases: &bases,
+                result: G1Affine::identity(),
+            },
+            MsmClaim {
+                coeffs: &coeffs,
+                bases: &bases,
+                result,
+            },
+        ];
+        assert_eq!(super::batch_verify_msm(&claims, &mut rng), Ok(()));
+
+        let claims = [
+            claims[0],
+            MsmClaim {
+                result: G1Affine::identity(),
+                ..claims[1]
+            },
+        ];
+        assert_eq!(super::batch_verify_msm(&claims, &mut rng), Err(1));
+    }
+
+    fn run_multiexp_cyclotomic<G: super::CyclotomicGroup>(generator: G, n: usize) {
+        let mut rng = OsRng;
+        let bases: Vec<G> = (0..n)
+            .map(|_| generator * G::Scalar::random(&mut rng))
+            .collect();
+        let mut coeffs: Vec<G::Scalar> = (0..n).map(|_| G::Scalar::random(&mut rng)).collect();
+        coeffs[0] = G::Scalar::ZERO;
+        coeffs[1] = G::Scalar::ONE;
+        coeffs[2] = -G::Scalar::ONE;
+
+        let expected = bases
+            .iter()
+            .zip(coeffs.iter())
+            .fold(G::identity(), |acc, (base, coeff)| acc + *base * coeff);
+        assert_eq!(super::multiexp_cyclotomic(&coeffs, &bases), expected);
+        assert_eq!(
+            super::multiexp_cyclotomic(&coeffs[..1], &bases[..1]),
+            G::identity()
+        );
+        assert_eq!(super::multiexp_cyclotomic::<G>(&[], &[]), G::identity());
+    }
+
+    #[test]
+    fn test_multiexp_cyclotomic() {
+        use pairing::Engine;
+
+        let g = crate::bn256::Bn256::pairing(
+            &crate::bn256::G1Affine::generator(),
+            &crate::bn256::G2Affine::generator(),
+        );
+        run_multiexp_cyclotomic(g, 40);
+        let mut rng = OsRng;
+        let bases = vec![g, g * crate::bn256::Fr::random(&mut rng)];
+        let coeffs = vec![crate::bn256::Fr::random(&mut rng); 2];
+        assert_eq!(
+            crate::bn256::Gt::multiexp(&coeffs, &bases),
+            bases[0] * coeffs[0] + bases[1] * coeffs[1]
+        );
+
+        run_multiexp_cyclotomic(crate::bls12_381::Gt::generator(), 40);
+        run_multiexp_cyclotomic(
+            crate::pluto_eris::Pluto::pairing(
+                &crate::pluto_eris::G1Affine::generator(),
+                &crate::pluto_eris::G2Affine::generator(),
+            ),
+            20,
+        );
+    }
 }
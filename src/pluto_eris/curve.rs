@@ -1,8 +1,12 @@
-use super::fields::{fp::Fp, fp2::Fp2, fq::Fq};
+use super::engine::{NEG_PLUTO_U, XI_TO_P_MINUS_1_OVER_2};
+use super::fields::{fp::Fp, fp2::Fp2, fp6::FROBENIUS_COEFF_FP6_C1, fq::Fq};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
-use crate::hash_to_curve::svdw_hash_to_curve;
+use crate::hash_to_curve::{
+    blake2b_hash_to_curve, hash_to_field, svdw_hash_to_curve, svdw_map_to_curve,
+    svdw_precomputed_constants, ExpandMessage, HashToCurveExt,
+};
 use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
 use core::cmp;
 use core::fmt::Debug;
@@ -156,6 +160,22 @@ impl G1 {
     const SVDW_Z: Fp = Fp::ONE;
 }
 
+/// Pluto has no reference suite, this one runs the generic SvdW map of RFC 9380 with
+/// `Z = 1` and its outputs are specific to this crate.
+impl HashToCurveExt for G1 {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fp]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    fn map_to_curve(u: &Fp) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fp; 4] = svdw_precomputed_constants::<G1>(G1::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, G1::SVDW_Z)
+    }
+}
+
 new_curve_impl!(
     (pub),
     Eris,
@@ -201,12 +221,12 @@ impl CofactorGroup for G2 {
     }
 
     fn is_torsion_free(&self) -> Choice {
-        // group order = p
+        // group order = q
         let e: [u8; 56] = [
             0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x30, 0xe0, 0x00, 0x0d, 0x7f,
             0x70, 0xe4, 0xa8, 0x03, 0xca, 0x76, 0xf4, 0x39, 0x26, 0x6f, 0x44, 0x3f, 0x9a, 0x5c,
-            0xda, 0x8a, 0x6c, 0x7b, 0xe4, 0xa7, 0xa5, 0xfe, 0x8f, 0xad, 0xff, 0xd6, 0xa2, 0xa7,
-            0xe8, 0xc3, 0x00, 0x06, 0xb9, 0x45, 0x9f, 0xff, 0xfc, 0xd3, 0x00, 0x00, 0x00, 0x01,
+            0x7a, 0x8a, 0x6c, 0x7b, 0xe4, 0xa7, 0x75, 0xfe, 0x8e, 0x17, 0x7f, 0xd6, 0x9c, 0xa7,
+            0xe8, 0x5d, 0x60, 0x05, 0x0a, 0xf4, 0x1f, 0xff, 0xfc, 0xd3, 0x00, 0x00, 0x00, 0x01,
         ];
         // self * GROUP_ORDER;
         let mut acc = G2::identity();
@@ -228,6 +248,21 @@ impl Eris {
     const SVDW_Z: Fq = Fq::ONE;
 }
 
+/// As for Pluto, a non-standard suite over the generic SvdW map with `Z = 1`.
+impl HashToCurveExt for Eris {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fq]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    fn map_to_curve(u: &Fq) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fq; 4] = svdw_precomputed_constants::<Eris>(Eris::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, Eris::SVDW_Z)
+    }
+}
+
 new_curve_impl!(
     (pub),
     G2,
@@ -239,9 +274,86 @@ new_curve_impl!(
     TRITON_A,
     TRITON_B,
     "triton",
-    |_, _| unimplemented!(),
+    |curve_id, domain_prefix| {
+        blake2b_hash_to_curve("SVDW", curve_id, domain_prefix, |[c0, c1]| Fp2 { c0, c1 })
+    },
 );
 
+impl G2 {
+    /// Constant Z for the Shallue-van de Woestijne map, the output of `find_z_svdw` from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#svdw-z-code> over `Fp2`, i.e. `-1`.
+    const SVDW_Z: Fp2 = Fp2 {
+        c0: Fp::from_raw([
+            0x9ffffcd300000000,
+            0xa2a7e8c30006b945,
+            0xe4a7a5fe8fadffd6,
+            0x443f9a5cda8a6c7b,
+            0xa803ca76f439266f,
+            0x0130e0000d7f70e4,
+            0x2400000000002400,
+        ]),
+        c1: Fp::ZERO,
+    };
+
+    /// The untwist-Frobenius-twist endomorphism
+    /// `psi(x, y) = (x^p * xi^((p - 1) / 3), y^p * xi^((p - 1) / 2))`.
+    fn psi(&self) -> Self {
+        let mut x = self.x;
+        x.conjugate();
+        let mut y = self.y;
+        y.conjugate();
+        let mut z = self.z;
+        z.conjugate();
+        G2 {
+            x: x * FROBENIUS_COEFF_FP6_C1[1],
+            y: y * XI_TO_P_MINUS_1_OVER_2,
+            z,
+        }
+    }
+
+    /// Multiplies by the Pluto parameter `u = -NEG_PLUTO_U`, which is public so there is
+    /// no need for a constant time ladder.
+    fn mul_by_u(&self) -> Self {
+        let mut acc = G2::identity();
+        for i in (0..128 - NEG_PLUTO_U.leading_zeros()).rev() {
+            acc = acc.double();
+            if (NEG_PLUTO_U >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+        -acc
+    }
+}
+
+impl HashToCurveExt for G2 {
+    /// Hashes into `Fp2` with `m = 2`, so each element is built from two consecutive
+    /// `Fp` elements of the expanded message.
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fp2]) {
+        let mut coeffs = vec![Fp::ZERO; 2 * output.len()];
+        hash_to_field::<_, X>(message, dst, &mut coeffs);
+        for (out, c) in output.iter_mut().zip(coeffs.chunks(2)) {
+            *out = Fp2 { c0: c[0], c1: c[1] };
+        }
+    }
+
+    fn map_to_curve(u: &Fp2) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fp2; 4] = svdw_precomputed_constants::<G2>(G2::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, G2::SVDW_Z)
+    }
+
+    /// Fast cofactor clearing for BN curves from section 6.1 of
+    /// <https://cacr.uwaterloo.ca/techreports/2011/cacr2011-26.pdf>:
+    /// `[u]P + psi([3u]P) + psi^2([u]P) + psi^3(P)`.
+    fn clear_h(&self) -> Self {
+        let u_p = self.mul_by_u();
+        let three_u_p = u_p.double() + u_p;
+        u_p + three_u_p.psi() + u_p.psi().psi() + self.psi().psi().psi()
+    }
+}
+
 #[test]
 fn test_curve_pluto() {
     crate::tests::curve::curve_tests::<G1>();
@@ -270,8 +382,50 @@ fn test_serialization() {
 
 #[test]
 fn test_hash_to_curve() {
+    crate::tests::curve::find_z_svdw_test::<G1>(G1::SVDW_Z);
+    crate::tests::curve::find_z_svdw_test::<Eris>(Eris::SVDW_Z);
     crate::tests::curve::hash_to_curve_test::<G1>();
+    crate::tests::curve::hash_to_curve_with_test::<G1>();
     crate::tests::curve::hash_to_curve_test::<Eris>();
+    crate::tests::curve::hash_to_curve_with_test::<Eris>();
+    crate::tests::curve::hash_to_curve_test::<G2>();
+    crate::tests::curve::hash_to_curve_with_test::<G2>();
+}
+
+#[test]
+fn test_hash_to_curve_triton() {
+    use crate::hash_to_curve::ExpandMsgXmd;
+
+    crate::tests::curve::find_z_svdw_test::<G2>(G2::SVDW_Z);
+
+    let g = G2::generator();
+    assert!(bool::from(g.is_torsion_free()));
+
+    // psi acts as multiplication by 6u^2 on the prime order subgroup.
+    let u = Fq::from_u128(NEG_PLUTO_U);
+    assert_eq!(g.psi(), g * (u.square() * Fq::from(6)));
+
+    let dst = b"QUUX-V01-CS02-with-Triton_XMD:SHA-256_SVDW_RO_";
+    for i in 0..100u32 {
+        let message = i.to_be_bytes();
+        let mut u = [Fp2::ZERO; 1];
+        G2::hash_to_field::<ExpandMsgXmd<sha2::Sha256>>(&message, dst, &mut u);
+
+        // Points from the map are on Triton but, the cofactor being about the size of `q`,
+        // not in the prime order subgroup until it is cleared.
+        let p = G2::map_to_curve(&u[0]);
+        assert!(bool::from(p.is_on_curve()));
+        assert!(!bool::from(p.is_torsion_free()));
+        assert!(bool::from(p.clear_h().is_torsion_free()));
+        assert!(bool::from(p.clear_cofactor().is_torsion_free()));
+
+        let q = G2::hash_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(&message, dst);
+        assert!(bool::from(q.is_torsion_free()));
+        let q = G2::encode_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(&message, dst);
+        assert!(bool::from(q.is_torsion_free()));
+        let q = G2::hash_to_curve("test")(&message);
+        assert!(bool::from(q.is_torsion_free()));
+    }
 }
 
 #[test]
@@ -2,6 +2,7 @@
 use crate::ff::{Field, PrimeField};
 use crate::group::cofactor::CofactorCurveAffine;
 use crate::group::Group;
+use crate::msm::{multiexp_cyclotomic, CyclotomicGroup};
 use crate::pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
 use crate::pluto_eris::curve::*;
 use crate::pluto_eris::fields::fp::*;
@@ -17,7 +18,7 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 /// Adaptation of Algorithm 1, https://eprint.iacr.org/2013/722.pdf
 /// the parameter for the curve Pluto: u = -0x4000000000001000008780000000
-const NEG_PLUTO_U: u128 = 0x4000000000001000008780000000;
+pub(crate) const NEG_PLUTO_U: u128 = 0x4000000000001000008780000000;
 
 const NEG_SIX_U_PLUS_2_NAF: [i8; 114] = [
     0, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -27,7 +28,7 @@ const NEG_SIX_U_PLUS_2_NAF: [i8; 114] = [
 ];
 
 /// Value of (57/(u + 3))^((p - 1)/2) where u^2 + 5 = 0 in Fp2.
-const XI_TO_P_MINUS_1_OVER_2: Fp2 = Fp2 {
+pub(crate) const XI_TO_P_MINUS_1_OVER_2: Fp2 = Fp2 {
     c0: Fp::from_raw([
         0x54cf5ad1c0926216,
         0x186c1f3ce4a46d4e,
@@ -106,6 +107,14 @@ impl Gt {
     pub fn double(&self) -> Gt {
         Gt(self.0.square())
     }
+
+    /// Computes $\sum_i k_i \cdot P_i$, i.e. the product $\prod_i P_i^{k_i}$ in
+    /// multiplicative notation, with a Pippenger multi-exponentiation.
+    ///
+    /// This function will panic if coeffs and bases have a different length.
+    pub fn multiexp(coeffs: &[Fq], bases: &[Gt]) -> Gt {
+        multiexp_cyclotomic(coeffs, bases)
+    }
 }
 
 impl<'a> Neg for &'a Gt {
@@ -212,6 +221,14 @@ impl Group for Gt {
     }
 }
 
+impl CyclotomicGroup for Gt {
+    fn cyclotomic_double(&self) -> Self {
+        let mut u = self.0;
+        u.cyclotomic_square();
+        Gt(u)
+    }
+}
+
 /// Points of G2 in Jacobian coordinates.
 /// These are points lie in the twisted curve E'(Fp2).
 #[derive(Clone, Debug)]
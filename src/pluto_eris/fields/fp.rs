@@ -204,6 +204,8 @@ field_bits_7_limbs!(Fp, MODULUS, MODULUS_LIMBS_32);
 
 extend_field_legendre!(Fp);
 
+impl crate::hash_to_curve::Sgn0 for Fp {}
+
 impl Fp {
     pub const fn size() -> usize {
         SIZE
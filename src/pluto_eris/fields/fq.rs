@@ -194,6 +194,8 @@ field_bits_7_limbs!(Fq, MODULUS, MODULUS_LIMBS_32);
 
 extend_field_legendre!(Fq);
 
+impl crate::hash_to_curve::Sgn0 for Fq {}
+
 impl Fq {
     /// Return field element size in bytes.
     pub const fn size() -> usize {
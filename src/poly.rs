@@ -0,0 +1,620 @@
+//! Dense univariate polynomials over prime fields, with FFT-based arithmetic
+//! built on [`EvaluationDomain`].
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::fft::EvaluationDomain;
+use ff::{BatchInvert, PrimeField};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use rayon::slice::ParallelSlice;
+
+/// Below this number of coefficients in the smaller operand, products are
+/// computed with the schoolbook method rather than with FFTs.
+const NAIVE_MUL_THRESHOLD: usize = 32;
+
+/// Below this quotient length, division uses the schoolbook long division
+/// rather than a Newton iteration.
+const NAIVE_DIV_THRESHOLD: usize = 64;
+
+/// Below this number of points or coefficients, [`DensePolynomial::evaluate_many`]
+/// uses Horner's rule rather than a subproduct tree.
+const SUBPRODUCT_TREE_THRESHOLD: usize = 256;
+
+/// A polynomial in coefficient form, from the constant term up.
+///
+/// Products use FFTs when the field has a large enough multiplicative subgroup
+/// of order a power of two, and the schoolbook method otherwise.
+///
+/// The coefficient vector never has trailing zeros, so the zero polynomial has
+/// no coefficients.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DensePolynomial<F: PrimeField> {
+    coeffs: Vec<F>,
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        DensePolynomial { coeffs: vec![] }
+    }
+
+    /// Creates a polynomial from its coefficients, from the constant term up.
+    pub fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
+        let mut poly = DensePolynomial { coeffs };
+        poly.truncate_leading_zeros();
+        poly
+    }
+
+    /// Creates a polynomial from its coefficients, from the constant term up.
+    pub fn from_coefficients_slice(coeffs: &[F]) -> Self {
+        Self::from_coefficients_vec(coeffs.to_vec())
+    }
+
+    /// Interpolates the polynomial of degree $< n$ that takes the values `evals`
+    /// over the points $\omega^i$ of `domain`.
+    ///
+    /// Panics if `evals` does not have exactly $n$ elements.
+    pub fn interpolate(domain: &EvaluationDomain<F>, evals: &[F]) -> Self {
+        let mut coeffs = evals.to_vec();
+        domain.ifft(&mut coeffs);
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Interpolates the polynomial of degree $< n$ that takes the values `evals`
+    /// over the points $g \omega^i$ of the coset of `domain`.
+    ///
+    /// Panics if `evals` does not have exactly $n$ elements.
+    pub fn interpolate_coset(domain: &EvaluationDomain<F>, evals: &[F]) -> Self {
+        let mut coeffs = evals.to_vec();
+        domain.coset_ifft(&mut coeffs);
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Returns the coefficients, from the constant term up.
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Consumes the polynomial and returns its coefficients.
+    pub fn into_coeffs(self) -> Vec<F> {
+        self.coeffs
+    }
+
+    /// Returns true if this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns the degree of the polynomial, which is $0$ for the zero
+    /// polynomial.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    fn truncate_leading_zeros(&mut self) {
+        while self
+            .coeffs
+            .last()
+            .map_or(false, |c| bool::from(c.is_zero()))
+        {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Evaluates the polynomial at `x`.
+    pub fn evaluate(&self, x: &F) -> F {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, coeff| acc * x + coeff)
+    }
+
+    /// Evaluates the polynomial at every point of `points`.
+    ///
+    /// Many points are evaluated with a [`SubproductTree`] in quasi-linear time,
+    /// and few with Horner's rule.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if points.len() < SUBPRODUCT_TREE_THRESHOLD || self.coeffs.len() < SUBPRODUCT_TREE_THRESHOLD
+        {
+            points.par_iter().map(|x| self.evaluate(x)).collect()
+        } else {
+            SubproductTree::new(points).evaluate(self)
+        }
+    }
+
+    /// Interpolates the polynomial of degree $< m$ that takes the values `evals`
+    /// at the $m$ distinct `points`, with a [`SubproductTree`].
+    ///
+    /// Panics if `points` and `evals` have a different length, or if the points
+    /// are not distinct.
+    pub fn interpolate_points(points: &[F], evals: &[F]) -> Self {
+        SubproductTree::new(points).interpolate(evals)
+    }
+
+    /// Returns the formal derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        Self::from_coefficients_vec(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| *c * F::from(i as u64))
+                .collect(),
+        )
+    }
+
+    /// Evaluates the polynomial over the points $\omega^i$ of `domain`.
+    ///
+    /// Coefficients of degree $\geq n$ are folded modulo $X^n - 1$ first, so the
+    /// polynomial may have any degree.
+    pub fn evaluate_over_domain(&self, domain: &EvaluationDomain<F>) -> Vec<F> {
+        let mut evals = self.fold(domain.size());
+        domain.fft(&mut evals);
+        evals
+    }
+
+    /// Returns the coefficients of the polynomial reduced modulo $X^n - 1$.
+    fn fold(&self, n: usize) -> Vec<F> {
+        let mut folded = vec![F::ZERO; n];
+        for chunk in self.coeffs.chunks(n) {
+            for (acc, coeff) in folded.iter_mut().zip(chunk.iter()) {
+                *acc += coeff;
+            }
+        }
+        folded
+    }
+
+    /// Multiplies every coefficient by `scalar`.
+    pub fn scale(&self, scalar: &F) -> Self {
+        Self::from_coefficients_vec(self.coeffs.iter().map(|c| *c * scalar).collect())
+    }
+
+    /// Returns the polynomial truncated to its first `len` coefficients, i.e.
+    /// reduced modulo $X^{len}$.
+    fn truncate(&self, len: usize) -> Self {
+        Self::from_coefficients_slice(&self.coeffs[..len.min(self.coeffs.len())])
+    }
+
+    /// Returns the polynomial with its `len` first coefficients reversed, i.e.
+    /// $X^{len - 1} p(1 / X)$ when the degree is below `len`.
+    fn reverse(&self, len: usize) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        coeffs.resize(len, F::ZERO);
+        coeffs.reverse();
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Returns the inverse of the polynomial modulo $X^{len}$.
+    ///
+    /// Panics if the constant term is zero.
+    fn inverse_mod_x_pow(&self, len: usize) -> Self {
+        let c0_inv = self.coeffs[0]
+            .invert()
+            .expect("constant term must be invertible");
+        // Newton iteration g <- g (2 - f g), doubling the precision each step
+        let mut inv = Self::from_coefficients_vec(vec![c0_inv]);
+        let mut precision = 1;
+        while precision < len {
+            precision = (2 * precision).min(len);
+            let fg = (&self.truncate(precision) * &inv).truncate(precision);
+            let two_minus_fg = &Self::from_coefficients_vec(vec![F::ONE.double()]) - &fg;
+            inv = (&inv * &two_minus_fg).truncate(precision);
+        }
+        inv
+    }
+
+    /// Divides the polynomial by `divisor`, returning the quotient and the
+    /// remainder.
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return (Self::zero(), self.clone());
+        }
+
+        let quotient_len = self.coeffs.len() - divisor.coeffs.len() + 1;
+        let quotient =
+            if quotient_len < NAIVE_DIV_THRESHOLD || divisor.coeffs.len() < NAIVE_MUL_THRESHOLD {
+                self.long_division(divisor)
+            } else {
+                // rev(q) = rev(a) / rev(b) mod X^{deg a - deg b + 1}
+                let rev_self = self.reverse(self.coeffs.len());
+                let rev_divisor = divisor.reverse(divisor.coeffs.len());
+                let rev_quotient = (&rev_self.truncate(quotient_len)
+                    * &rev_divisor.inverse_mod_x_pow(quotient_len))
+                    .truncate(quotient_len);
+                rev_quotient.reverse(quotient_len)
+            };
+        let remainder = self - &(&quotient * divisor);
+        (quotient, remainder)
+    }
+
+    /// Returns the quotient of the schoolbook long division by `divisor`.
+    fn long_division(&self, divisor: &Self) -> Self {
+        let lead_inv = divisor.coeffs.last().unwrap().invert().unwrap();
+        let mut rem = self.coeffs.clone();
+        let quotient_len = rem.len() - divisor.coeffs.len() + 1;
+        let mut quotient = vec![F::ZERO; quotient_len];
+        for i in (0..quotient_len).rev() {
+            let q = rem[i + divisor.coeffs.len() - 1] * lead_inv;
+            for (r, d) in rem[i..].iter_mut().zip(divisor.coeffs.iter()) {
+                *r -= q * d;
+            }
+            quotient[i] = q;
+        }
+        Self::from_coefficients_vec(quotient)
+    }
+
+    /// Divides the polynomial by $X^n - c$, returning the quotient and the
+    /// remainder, in linear time.
+    ///
+    /// For $c = 1$ this is the division by the vanishing polynomial of the
+    /// subgroup of size $n$, and for $c = g^n$ by the one of its coset by $g$.
+    ///
+    /// Panics if `n` is zero.
+    pub fn divide_by_vanishing_poly(&self, n: usize, c: &F) -> (Self, Self) {
+        assert!(n > 0);
+        if self.coeffs.len() <= n {
+            return (Self::zero(), self.clone());
+        }
+        let mut rem = self.coeffs.clone();
+        let mut quotient = vec![F::ZERO; rem.len() - n];
+        for i in (n..rem.len()).rev() {
+            let q = rem[i];
+            quotient[i - n] = q;
+            rem[i - n] += q * c;
+        }
+        rem.truncate(n);
+        (
+            Self::from_coefficients_vec(quotient),
+            Self::from_coefficients_vec(rem),
+        )
+    }
+
+    /// Multiplies two nonzero polynomials with the schoolbook method.
+    fn naive_mul(&self, other: &Self) -> Self {
+        let mut coeffs = vec![F::ZERO; self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (c, b) in coeffs[i..].iter_mut().zip(other.coeffs.iter()) {
+                *c += *a * b;
+            }
+        }
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Multiplies two nonzero polynomials by pointwise multiplication of their
+    /// evaluations over a large enough subgroup.
+    fn fft_mul(&self, other: &Self) -> Self {
+        let len = self.coeffs.len() + other.coeffs.len() - 1;
+        let domain = EvaluationDomain::new(len.next_power_of_two().trailing_zeros());
+
+        let mut columns = [self.coeffs.clone(), other.coeffs.clone()];
+        for column in columns.iter_mut() {
+            column.resize(domain.size(), F::ZERO);
+        }
+        domain.fft_batch(&mut columns);
+        let [mut product, other] = columns;
+        for (a, b) in product.iter_mut().zip(other.iter()) {
+            *a *= b;
+        }
+        domain.ifft(&mut product);
+        product.truncate(len);
+        Self::from_coefficients_vec(product)
+    }
+}
+
+impl<'a, 'b, F: PrimeField> Add<&'b DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: &'b DensePolynomial<F>) -> DensePolynomial<F> {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (a, b) in coeffs.iter_mut().zip(short.coeffs.iter()) {
+            *a += b;
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+impl<'a, 'b, F: PrimeField> Sub<&'b DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: &'b DensePolynomial<F>) -> DensePolynomial<F> {
+        self + &(-rhs)
+    }
+}
+
+impl<'a, F: PrimeField> Neg for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn neg(self) -> DensePolynomial<F> {
+        DensePolynomial {
+            coeffs: self.coeffs.iter().map(|c| -*c).collect(),
+        }
+    }
+}
+
+impl<'a, 'b, F: PrimeField> Mul<&'b DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, rhs: &'b DensePolynomial<F>) -> DensePolynomial<F> {
+        if self.is_zero() || rhs.is_zero() {
+            DensePolynomial::zero()
+        } else if self.coeffs.len().min(rhs.coeffs.len()) < NAIVE_MUL_THRESHOLD
+            // fields of low 2-adicity have no subgroup to evaluate the product on
+            || (self.coeffs.len() + rhs.coeffs.len() - 1).next_power_of_two().trailing_zeros() > F::S
+        {
+            self.naive_mul(rhs)
+        } else {
+            self.fft_mul(rhs)
+        }
+    }
+}
+
+/// The subproduct tree of points $x_0, \dots, x_{m-1}$: the leaves are the
+/// polynomials $X - x_i$ and every other node is the product of its children,
+/// so that the root is $\prod_i (X - x_i)$.
+///
+/// Going down the tree with remainders gives multipoint evaluation, and going
+/// up with linear combinations gives interpolation, both in
+/// $O(M(m) \log m)$ where $M$ is the cost of [`DensePolynomial`] products.
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F: PrimeField> {
+    /// Layers from the leaves up to the root. A node without a sibling is
+    /// carried up unchanged.
+    layers: Vec<Vec<DensePolynomial<F>>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Builds the subproduct tree of `points`.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn new(points: &[F]) -> Self {
+        let mut layers = vec![points
+            .iter()
+            .map(|x| DensePolynomial::from_coefficients_vec(vec![-*x, F::ONE]))
+            .collect::<Vec<_>>()];
+        while layers.last().unwrap().len() > 1 {
+            let layer = layers
+                .last()
+                .unwrap()
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(layer);
+        }
+        SubproductTree { layers }
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// Returns true if the tree has no points.
+    pub fn is_empty(&self) -> bool {
+        self.layers[0].is_empty()
+    }
+
+    /// Returns the vanishing polynomial $\prod_i (X - x_i)$ of the points.
+    pub fn vanishing_polynomial(&self) -> DensePolynomial<F> {
+        self.layers
+            .last()
+            .unwrap()
+            .first()
+            .cloned()
+            .unwrap_or_else(|| DensePolynomial::from_coefficients_vec(vec![F::ONE]))
+    }
+
+    /// Evaluates `poly` at every point of the tree.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn evaluate(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        if self.is_empty() {
+            return vec![];
+        }
+        // poly mod every node, from the root down to the leaves
+        let mut remainders = vec![poly.div_rem(&self.layers.last().unwrap()[0]).1];
+        for layer in self.layers.iter().rev().skip(1) {
+            remainders = layer
+                .par_iter()
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].div_rem(node).1)
+                .collect();
+        }
+        remainders
+            .into_iter()
+            .map(|r| r.coeffs.first().copied().unwrap_or(F::ZERO))
+            .collect()
+    }
+
+    /// Interpolates the polynomial of degree $< m$ that takes the values `evals`
+    /// at the points of the tree.
+    ///
+    /// Panics if `evals` does not have exactly $m$ elements, or if the points
+    /// are not distinct.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn interpolate(&self, evals: &[F]) -> DensePolynomial<F> {
+        assert_eq!(evals.len(), self.len());
+        if self.is_empty() {
+            return DensePolynomial::zero();
+        }
+
+        // Lagrange weights y_i / m'(x_i), with m the vanishing polynomial
+        let mut weights = self.evaluate(&self.vanishing_polynomial().derivative());
+        weights.iter_mut().batch_invert();
+        let mut nodes: Vec<_> = weights
+            .iter()
+            .zip(evals.iter())
+            .map(|(w, y)| {
+                assert!(
+                    !bool::from(w.is_zero()),
+                    "interpolation points must be distinct"
+                );
+                DensePolynomial::from_coefficients_vec(vec![*w * y])
+            })
+            .collect();
+
+        // left * m_right + right * m_left, from the leaves up to the root
+        for layer in self.layers.iter().take(self.layers.len() - 1) {
+            nodes = nodes
+                .par_chunks(2)
+                .zip(layer.par_chunks(2))
+                .map(|(pair, vanishing)| match (pair, vanishing) {
+                    ([left, right], [m_left, m_right]) => &(left * m_right) + &(right * m_left),
+                    ([node], [_]) => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        nodes.pop().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DensePolynomial;
+    use crate::bn256::Fr;
+    use crate::fft::EvaluationDomain;
+    use ff::Field;
+    use rand_core::OsRng;
+
+    fn random_poly(len: usize) -> DensePolynomial<Fr> {
+        DensePolynomial::from_coefficients_vec((0..len).map(|_| Fr::random(OsRng)).collect())
+    }
+
+    #[test]
+    fn test_mul() {
+        for (a, b) in [(0, 5), (1, 1), (3, 7), (40, 33), (100, 257), (64, 64)] {
+            let a = random_poly(a);
+            let b = random_poly(b);
+            let product = &a * &b;
+            if !a.is_zero() {
+                assert_eq!(product, a.naive_mul(&b));
+            }
+            let x = Fr::random(OsRng);
+            assert_eq!(product.evaluate(&x), a.evaluate(&x) * b.evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        for (a, b) in [(5, 7), (10, 3), (100, 40), (300, 100), (200, 1)] {
+            let a = random_poly(a);
+            let b = random_poly(b);
+            let (q, r) = a.div_rem(&b);
+            assert!(r.is_zero() || r.degree() < b.degree());
+            assert_eq!(&(&q * &b) + &r, a);
+            if a.coeffs().len() >= b.coeffs().len() {
+                assert_eq!(q, a.long_division(&b));
+            }
+        }
+
+        // exact division
+        let a = random_poly(150);
+        let b = random_poly(80);
+        let (q, r) = (&a * &b).div_rem(&b);
+        assert_eq!(q, a);
+        assert!(r.is_zero());
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_poly() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let n = domain.size();
+        let c = Fr::random(OsRng);
+        let vanishing = {
+            let mut coeffs = vec![Fr::ZERO; n + 1];
+            coeffs[0] = -c;
+            coeffs[n] = Fr::ONE;
+            DensePolynomial::from_coefficients_vec(coeffs)
+        };
+        for len in [3, 16, 17, 50] {
+            let a = random_poly(len);
+            let (q, r) = a.divide_by_vanishing_poly(n, &c);
+            assert_eq!((q.clone(), r.clone()), a.div_rem(&vanishing));
+            assert_eq!(&(&q * &vanishing) + &r, a);
+        }
+
+        // a multiple of the vanishing polynomial of the domain vanishes on it
+        let a = &random_poly(20) * &vanishing;
+        let (_, r) = a.divide_by_vanishing_poly(n, &c);
+        assert!(r.is_zero());
+    }
+
+    #[test]
+    fn test_evaluate_and_interpolate() {
+        let domain = EvaluationDomain::<Fr>::new(5);
+        let a = random_poly(domain.size());
+        let points: Vec<_> = (0..10).map(|_| Fr::random(OsRng)).collect();
+        let evals = a.evaluate_many(&points);
+        for (x, eval) in points.iter().zip(evals.iter()) {
+            assert_eq!(a.evaluate(x), *eval);
+        }
+
+        let evals = a.evaluate_over_domain(&domain);
+        let mut x = Fr::ONE;
+        for eval in evals.iter() {
+            assert_eq!(a.evaluate(&x), *eval);
+            x *= domain.omega();
+        }
+        assert_eq!(DensePolynomial::interpolate(&domain, &evals), a);
+
+        // polynomials of higher degree are folded
+        let b = random_poly(3 * domain.size() + 5);
+        let evals = b.evaluate_over_domain(&domain);
+        assert_eq!(evals[7], b.evaluate(&domain.omega().pow_vartime([7])));
+
+        let mut coset_evals = a.coeffs().to_vec();
+        domain.coset_fft(&mut coset_evals);
+        assert_eq!(DensePolynomial::interpolate_coset(&domain, &coset_evals), a);
+    }
+
+    fn run_subproduct_tree<F: ff::PrimeField>() {
+        use super::SubproductTree;
+
+        for (num_points, len) in [(0, 5), (1, 3), (7, 20), (300, 257), (257, 1000)] {
+            let points: Vec<F> = (0..num_points).map(|_| F::random(OsRng)).collect();
+            let poly = DensePolynomial::from_coefficients_vec(
+                (0..len).map(|_| F::random(OsRng)).collect(),
+            );
+            let tree = SubproductTree::new(&points);
+            assert_eq!(tree.len(), num_points);
+            let expected: Vec<_> = points.iter().map(|x| poly.evaluate(x)).collect();
+            assert_eq!(tree.evaluate(&poly), expected);
+            assert_eq!(poly.evaluate_many(&points), expected);
+            for x in points.iter() {
+                assert!(bool::from(
+                    tree.vanishing_polynomial().evaluate(x).is_zero()
+                ));
+            }
+
+            let interpolated = DensePolynomial::interpolate_points(&points, &expected);
+            assert!(interpolated.is_zero() || interpolated.degree() < num_points);
+            if num_points >= len {
+                assert_eq!(interpolated, poly);
+            } else {
+                assert_eq!(interpolated.evaluate_many(&points), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_subproduct_tree() {
+        run_subproduct_tree::<Fr>();
+        run_subproduct_tree::<crate::bls12_381::Fr>();
+        run_subproduct_tree::<crate::secp256k1::Fq>();
+    }
+}
@@ -1,7 +1,10 @@
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
-use crate::hash_to_curve::svdw_hash_to_curve;
+use crate::hash_to_curve::{
+    hash_to_field, simple_svdw_map_to_xy, svdw_hash_to_curve, svdw_map_to_curve,
+    svdw_precomputed_constants, ExpandMessage, ExpandMsgXmd, HashToCurveExt,
+};
 use crate::secp256k1::Fp;
 use crate::secp256k1::Fq;
 use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
@@ -48,6 +51,105 @@ const SECP_GENERATOR_Y: Fp = Fp::from_raw([
 const SECP_A: Fp = Fp::from_raw([0, 0, 0, 0]);
 const SECP_B: Fp = Fp::from_raw([7, 0, 0, 0]);
 
+// Constants for the `secp256k1_XMD:SHA-256_SSWU_` suites, which map onto the curve
+// `E': y^2 = x^3 + ISO_A * x + ISO_B` and then through a 3-isogeny onto secp256k1.
+// Reference: <https://datatracker.ietf.org/doc/html/rfc9380#appendix-E.1>
+const ISO_A: Fp = Fp::from_raw([
+    0x405447c01a444533,
+    0xe953d363cb6f0e5d,
+    0xa08a5558f0f5d272,
+    0x3f8731abdd661adc,
+]);
+const ISO_B: Fp = Fp::from_raw([1771, 0, 0, 0]);
+
+// Coefficients of the isogeny map, constant term first.
+const ISO_X_NUM: [Fp; 4] = [
+    Fp::from_raw([
+        0x8e38e38daaaaa8c7,
+        0x38e38e38e38e38e3,
+        0xe38e38e38e38e38e,
+        0x8e38e38e38e38e38,
+    ]),
+    Fp::from_raw([
+        0xdfff1044f17c6581,
+        0xd595d2fc0bf63b92,
+        0xb9f315cea7fd44c5,
+        0x07d3d4c80bc321d5,
+    ]),
+    Fp::from_raw([
+        0x4ecbd0b53d9dd262,
+        0xe4506144037c4031,
+        0xe2a413deca25caec,
+        0x534c328d23f234e6,
+    ]),
+    Fp::from_raw([
+        0x8e38e38daaaaa88c,
+        0x38e38e38e38e38e3,
+        0xe38e38e38e38e38e,
+        0x8e38e38e38e38e38,
+    ]),
+];
+const ISO_X_DEN: [Fp; 2] = [
+    Fp::from_raw([
+        0x9fe6b745781eb49b,
+        0x86cd409542f8487d,
+        0x9ca34ccbb7b640dd,
+        0xd35771193d94918a,
+    ]),
+    Fp::from_raw([
+        0xc52a56612a8c6d14,
+        0x06d36b641f5e41bb,
+        0xf7c4b2d51b542254,
+        0xedadc6f64383dc1d,
+    ]),
+];
+const ISO_Y_NUM: [Fp; 4] = [
+    Fp::from_raw([
+        0xa12f684b8e38e23c,
+        0x2f684bda12f684bd,
+        0x684bda12f684bda1,
+        0x4bda12f684bda12f,
+    ]),
+    Fp::from_raw([
+        0xdffc90fc201d71a3,
+        0x647ab046d686da6f,
+        0xa9d0a54b12a0a6d5,
+        0xc75e0c32d5cb7c0f,
+    ]),
+    Fp::from_raw([
+        0xa765e85a9ecee931,
+        0x722830a201be2018,
+        0x715209ef6512e576,
+        0x29a6194691f91a73,
+    ]),
+    Fp::from_raw([
+        0x84bda12f38e38d84,
+        0xbda12f684bda12f6,
+        0xa12f684bda12f684,
+        0x2f684bda12f684bd,
+    ]),
+];
+const ISO_Y_DEN: [Fp; 3] = [
+    Fp::from_raw([
+        0xfffffffefffff93b,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]),
+    Fp::from_raw([
+        0xdfb425d2685c2573,
+        0x9467c1bfc8e8d978,
+        0xd5e9e6632722c298,
+        0x7a06534bb8bdb49f,
+    ]),
+    Fp::from_raw([
+        0xa7bf8192bfd2a76f,
+        0x0a3d21162f0d6299,
+        0xf3a70c3fa8fe337e,
+        0x6484aa716545ca2c,
+    ]),
+];
+
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
     impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
@@ -70,6 +172,74 @@ new_curve_impl!(
 
 impl Secp256k1 {
     const SVDW_Z: Fp = Fp::ONE;
+
+    // Z = -11 (reference: <https://datatracker.ietf.org/doc/html/rfc9380#section-8.7>)
+    const SSWU_Z: Fp = Fp::from_raw([
+        0xfffffffefffffc24,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+
+    /// Hashes `message` to the curve with the `secp256k1_XMD:SHA-256_SSWU_RO_` suite from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#section-8.7>.
+    pub fn hash_to_curve_sswu_ro(message: &[u8], dst: &[u8]) -> Self {
+        let mut us = [Fp::ZERO; 2];
+        hash_to_field::<_, ExpandMsgXmd<sha2::Sha256>>(message, dst, &mut us);
+        Self::map_to_curve_sswu(&us[0]) + Self::map_to_curve_sswu(&us[1])
+    }
+
+    /// Encodes `message` to the curve with the `secp256k1_XMD:SHA-256_SSWU_NU_` suite from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#section-8.7>.
+    pub fn encode_to_curve_sswu_nu(message: &[u8], dst: &[u8]) -> Self {
+        let mut us = [Fp::ZERO; 1];
+        hash_to_field::<_, ExpandMsgXmd<sha2::Sha256>>(message, dst, &mut us);
+        Self::map_to_curve_sswu(&us[0])
+    }
+
+    /// Simplified SWU map onto `E'` followed by the 3-isogeny onto secp256k1.
+    fn map_to_curve_sswu(u: &Fp) -> Self {
+        fn horner(coeffs: &[Fp], x: Fp) -> Fp {
+            coeffs.iter().rev().fold(Fp::ZERO, |acc, c| acc * x + c)
+        }
+
+        let (x, y) = simple_svdw_map_to_xy(*u, ISO_A, ISO_B, Self::SSWU_Z);
+
+        // The denominators are monic.
+        let x_den = horner(&ISO_X_DEN, x) + x.square();
+        let y_den = horner(&ISO_Y_DEN, x) + x.square() * x;
+        let den_inv = (x_den * y_den).invert();
+        let is_exceptional = den_inv.is_none();
+        let den_inv = den_inv.unwrap_or(Fp::ZERO);
+
+        let x_iso = horner(&ISO_X_NUM, x) * y_den * den_inv;
+        let y_iso = y * horner(&ISO_Y_NUM, x) * x_den * den_inv;
+
+        let p = Secp256k1 {
+            x: x_iso,
+            y: y_iso,
+            z: Fp::ONE,
+        };
+        Secp256k1::conditional_select(&p, &Secp256k1::identity(), is_exceptional)
+    }
+}
+
+/// This is not the standard `secp256k1_XMD:SHA-256_SSWU_*` suite of RFC 9380, which is
+/// available as [`Secp256k1::hash_to_curve_sswu_ro`] and
+/// [`Secp256k1::encode_to_curve_sswu_nu`]. It uses the generic SvdW map with `Z = 1`
+/// instead, so its outputs won't match other secp256k1 implementations.
+impl HashToCurveExt for Secp256k1 {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fp]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    fn map_to_curve(u: &Fp) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fp; 4] = svdw_precomputed_constants::<Secp256k1>(Secp256k1::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, Secp256k1::SVDW_Z)
+    }
 }
 
 #[test]
@@ -80,6 +250,98 @@ fn test_curve() {
 #[test]
 fn test_hash_to_curve() {
     crate::tests::curve::hash_to_curve_test::<Secp256k1>();
+    crate::tests::curve::hash_to_curve_with_test::<Secp256k1>();
+}
+
+#[test]
+fn test_hash_to_curve_sswu() {
+    // `secp256k1_XMD:SHA-256_SSWU_RO_` vectors from
+    // https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.8.1
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+    crate::tests::curve::hash_to_curve_vector_test(
+        |message| Secp256k1::hash_to_curve_sswu_ro(message, dst),
+        [
+            (
+                "".to_string(),
+                (
+                    "0xc1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                    "0x64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+                ),
+            ),
+            (
+                "abc".to_string(),
+                (
+                    "0x3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                    "0x7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+                ),
+            ),
+            (
+                "abcdef0123456789".to_string(),
+                (
+                    "0xbac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+                    "0x4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+                ),
+            ),
+            (
+                format!("q128_{}", "q".repeat(128)),
+                (
+                    "0xe2167bc785333a37aa562f021f1e881defb853839babf52a7f72b102e41890e9",
+                    "0xf2401dd95cc35867ffed4f367cd564763719fbc6a53e969fb8496a1e6685d873",
+                ),
+            ),
+            (
+                format!("a512_{}", "a".repeat(512)),
+                (
+                    "0xe3c8d35aaaf0b9b647e88a0a0a7ee5d5bed5ad38238152e4e6fd8c1f8cb7c998",
+                    "0x8446eeb6181bf12f56a9d24e262221cc2f0c4725c7e3803024b5888ee5823aa6",
+                ),
+            ),
+        ],
+    );
+
+    // `secp256k1_XMD:SHA-256_SSWU_NU_` vectors from
+    // https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.8.2
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_NU_";
+    crate::tests::curve::hash_to_curve_vector_test(
+        |message| Secp256k1::encode_to_curve_sswu_nu(message, dst),
+        [
+            (
+                "".to_string(),
+                (
+                    "0xa4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+                    "0x62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+                ),
+            ),
+            (
+                "abc".to_string(),
+                (
+                    "0x3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+                    "0x902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+                ),
+            ),
+            (
+                "abcdef0123456789".to_string(),
+                (
+                    "0x07644fa6281c694709f53bdd21bed94dab995671e4a8cd1904ec4aa50c59bfdf",
+                    "0xc79f8d1dad79b6540426922f7fbc9579c3018dafeffcd4552b1626b506c21e7b",
+                ),
+            ),
+            (
+                format!("q128_{}", "q".repeat(128)),
+                (
+                    "0xb734f05e9b9709ab631d960fa26d669c4aeaea64ae62004b9d34f483aa9acc33",
+                    "0x03fc8a4a5a78632e2eb4d8460d69ff33c1d72574b79a35e402e801f2d0b1d6ee",
+                ),
+            ),
+            (
+                format!("a512_{}", "a".repeat(512)),
+                (
+                    "0x17d22b867658977b5002dbe8d0ee70a8cfddec3eec50fb93f36136070fd9fa6c",
+                    "0xe9178ff02f4dab73480f8dd590328aea99856a7b6cc8e5a6cdf289ecc2a51718",
+                ),
+            ),
+        ],
+    );
 }
 
 #[test]
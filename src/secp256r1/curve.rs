@@ -1,7 +1,10 @@
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
-use crate::hash_to_curve::simple_svdw_hash_to_curve;
+use crate::hash_to_curve::{
+    hash_to_field, simple_svdw_hash_to_curve, simple_svdw_map_to_curve, ExpandMessage,
+    ExpandMsgXmd, HashToCurveExt,
+};
 use crate::secp256r1::Fp;
 use crate::secp256r1::Fq;
 use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
@@ -91,6 +94,30 @@ impl Secp256r1 {
     ]);
 }
 
+impl Secp256r1 {
+    /// Hashes `message` to the curve with the `P256_XMD:SHA-256_SSWU_RO_` suite from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#section-8.2>.
+    pub fn hash_to_curve_sswu_ro(message: &[u8], dst: &[u8]) -> Self {
+        Self::hash_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(message, dst)
+    }
+
+    /// Encodes `message` to the curve with the `P256_XMD:SHA-256_SSWU_NU_` suite from
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#section-8.2>.
+    pub fn encode_to_curve_sswu_nu(message: &[u8], dst: &[u8]) -> Self {
+        Self::encode_to_curve_with::<ExpandMsgXmd<sha2::Sha256>>(message, dst)
+    }
+}
+
+impl HashToCurveExt for Secp256r1 {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fp]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    fn map_to_curve(u: &Fp) -> Self {
+        simple_svdw_map_to_curve(*u, Secp256r1::SSVDW_Z)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +129,96 @@ mod tests {
     #[test]
     fn test_hash_to_curve() {
         crate::tests::curve::hash_to_curve_test::<Secp256r1>();
+        crate::tests::curve::hash_to_curve_with_test::<Secp256r1>();
+    }
+
+    #[test]
+    fn test_hash_to_curve_sswu() {
+        // `P256_XMD:SHA-256_SSWU_RO_` vectors from https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.1.1
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+        crate::tests::curve::hash_to_curve_vector_test(
+            |message| Secp256r1::hash_to_curve_sswu_ro(message, dst),
+            [
+                (
+                    "".to_string(),
+                    (
+                        "0x2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                        "0x8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+                    ),
+                ),
+                (
+                    "abc".to_string(),
+                    (
+                        "0x0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                        "0x5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+                    ),
+                ),
+                (
+                    "abcdef0123456789".to_string(),
+                    (
+                        "0x65038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80",
+                        "0xcad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3",
+                    ),
+                ),
+                (
+                    format!("q128_{}", "q".repeat(128)),
+                    (
+                        "0x4be61ee205094282ba8a2042bcb48d88dfbb609301c49aa8b078533dc65a0b5d",
+                        "0x98f8df449a072c4721d241a3b1236d3caccba603f916ca680f4539d2bfb3c29e",
+                    ),
+                ),
+                (
+                    format!("a512_{}", "a".repeat(512)),
+                    (
+                        "0x457ae2981f70ca85d8e24c308b14db22f3e3862c5ea0f652ca38b5e49cd64bc5",
+                        "0xecb9f0eadc9aeed232dabc53235368c1394c78de05dd96893eefa62b0f4757dc",
+                    ),
+                ),
+            ],
+        );
+
+        // `P256_XMD:SHA-256_SSWU_NU_` vectors from https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.1.2
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_";
+        crate::tests::curve::hash_to_curve_vector_test(
+            |message| Secp256r1::encode_to_curve_sswu_nu(message, dst),
+            [
+                (
+                    "".to_string(),
+                    (
+                        "0xf871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1",
+                        "0x87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b",
+                    ),
+                ),
+                (
+                    "abc".to_string(),
+                    (
+                        "0xfc3f5d734e8dce41ddac49f47dd2b8a57257522a865c124ed02b92b5237befa4",
+                        "0xfe4d197ecf5a62645b9690599e1d80e82c500b22ac705a0b421fac7b47157866",
+                    ),
+                ),
+                (
+                    "abcdef0123456789".to_string(),
+                    (
+                        "0xf164c6674a02207e414c257ce759d35eddc7f55be6d7f415e2cc177e5d8faa84",
+                        "0x3aa274881d30db70485368c0467e97da0e73c18c1d00f34775d012b6fcee7f97",
+                    ),
+                ),
+                (
+                    format!("q128_{}", "q".repeat(128)),
+                    (
+                        "0x324532006312be4f162614076460315f7a54a6f85544da773dc659aca0311853",
+                        "0x8d8197374bcd52de2acfefc8a54fe2c8d8bebd2a39f16be9b710e4b1af6ef883",
+                    ),
+                ),
+                (
+                    format!("a512_{}", "a".repeat(512)),
+                    (
+                        "0x5c4bad52f81f39c8e8de1260e9a06d72b8b00a0829a8ea004a610b0691bea5d9",
+                        "0xc801e7c0782af1f74f24fc385a8555da0582032a3ce038de637ccdcb16f7ef7b",
+                    ),
+                ),
+            ],
+        );
     }
 
     #[test]
@@ -308,6 +308,8 @@ impl WithSmallOrderMulGroup<3> for Fp {
 
 extend_field_legendre!(Fp);
 
+impl crate::hash_to_curve::Sgn0 for Fp {}
+
 #[cfg(test)]
 mod test {
     use super::*;
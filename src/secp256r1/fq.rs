@@ -292,6 +292,8 @@ impl WithSmallOrderMulGroup<3> for Fq {
 
 extend_field_legendre!(Fq);
 
+impl crate::hash_to_curve::Sgn0 for Fq {}
+
 #[cfg(test)]
 mod test {
     use super::*;
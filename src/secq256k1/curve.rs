@@ -2,7 +2,10 @@ use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::Curve;
 use crate::group::{prime::PrimeCurveAffine, Group, GroupEncoding};
-use crate::hash_to_curve::svdw_hash_to_curve;
+use crate::hash_to_curve::{
+    hash_to_field, svdw_hash_to_curve, svdw_map_to_curve, svdw_precomputed_constants,
+    ExpandMessage, HashToCurveExt,
+};
 use crate::secp256k1::{Fp, Fq};
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
@@ -71,6 +74,22 @@ impl Secq256k1 {
     const SVDW_Z: Fq = Fq::ONE;
 }
 
+/// Non-standard suite: RFC 9380 does not cover secq256k1, and this crate picks the
+/// generic SvdW map with `Z = 1` for it, so outputs are not interoperable.
+impl HashToCurveExt for Secq256k1 {
+    fn hash_to_field<X: ExpandMessage>(message: &[u8], dst: &[u8], output: &mut [Fq]) {
+        hash_to_field::<_, X>(message, dst, output);
+    }
+
+    fn map_to_curve(u: &Fq) -> Self {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fq; 4] = svdw_precomputed_constants::<Secq256k1>(Secq256k1::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(*u, c1, c2, c3, c4, Secq256k1::SVDW_Z)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::secq256k1::Fq;
@@ -82,6 +101,7 @@ mod tests {
     #[test]
     fn test_hash_to_curve() {
         crate::tests::curve::hash_to_curve_test::<Secq256k1>();
+        crate::tests::curve::hash_to_curve_with_test::<Secq256k1>();
     }
 
     #[test]
@@ -3,6 +3,7 @@
 use crate::ff::Field;
 use crate::ff_ext::Legendre;
 use crate::group::prime::PrimeCurveAffine;
+use crate::hash_to_curve::{Blake2b512, ExpandMessage, ExpandMsgXmd, ExpandMsgXof, HashToCurveExt};
 use crate::tests::fe_from_str;
 use crate::{group::GroupEncoding, serde::SerdeObject};
 use crate::{hash_to_curve, CurveAffine, CurveExt};
@@ -352,12 +353,75 @@ pub fn hash_to_curve_test<G: CurveExt>() {
     }
 }
 
+/// Checks that [`HashToCurveExt`] is deterministic, domain separated and lands on the
+/// curve for every expander. This says nothing about interoperability: suites with a
+/// reference implementation are also checked against its vectors with
+/// [`hash_to_curve_vector_test`].
+pub fn hash_to_curve_with_test<G: HashToCurveExt>() {
+    fn check<G: HashToCurveExt, X: ExpandMessage>(message: &[u8]) {
+        let dst = b"QUUX-V01-CS02-with-expander";
+        let p = G::hash_to_curve_with::<X>(message, dst);
+        assert!(bool::from(p.is_on_curve()));
+        assert_eq!(p, G::hash_to_curve_with::<X>(message, dst));
+        assert_ne!(p, G::hash_to_curve_with::<X>(message, b"another-dst"));
+        assert!(bool::from(
+            G::encode_to_curve_with::<X>(message, dst).is_on_curve()
+        ));
+    }
+
+    let mut rng = OsRng;
+    for _ in 0..100 {
+        let message = iter::repeat_with(|| rng.next_u32().to_be_bytes())
+            .take(32)
+            .flatten()
+            .collect::<Vec<_>>();
+        check::<G, ExpandMsgXmd<sha2::Sha256>>(&message);
+        check::<G, ExpandMsgXof<sha3::Shake128>>(&message);
+        check::<G, ExpandMsgXmd<Blake2b512>>(&message);
+    }
+}
+
+/// Checks that `z` is the constant returned by `find_z_svdw` from
+/// <https://datatracker.ietf.org/doc/html/rfc9380#svdw-z-code> for the curve `G`.
+pub fn find_z_svdw_test<G: CurveExt>(z: G::Base)
+where
+    G::Base: Legendre,
+{
+    let [a, b] = [G::a(), G::b()];
+    let [three, four] = [3, 4].map(G::Base::from);
+    let g = |x: G::Base| (x.square() + a) * x + b;
+    let h = |z: G::Base| -(three * z.square() + four * a) * (four * g(z)).invert().unwrap();
+    let is_square = |x: G::Base| bool::from(x.ct_quadratic_residue());
+
+    let found = (1..)
+        .flat_map(|ctr| [G::Base::from(ctr), -G::Base::from(ctr)])
+        .find(|z| {
+            // criteria 1 to 4
+            !bool::from(g(*z).is_zero())
+                && !bool::from(h(*z).is_zero())
+                && is_square(h(*z))
+                && (is_square(g(*z)) || is_square(g(-*z * G::Base::from(2).invert().unwrap())))
+        })
+        .unwrap();
+    assert_eq!(found, z);
+}
+
+pub fn hash_to_curve_vector_test<G: CurveExt>(
+    hasher: impl Fn(&[u8]) -> G,
+    test_vector: impl IntoIterator<Item = (impl AsRef<[u8]>, (&'static str, &'static str))>,
+) {
+    for (message, (x, y)) in test_vector.into_iter() {
+        let expected = G::AffineExt::from_xy(fe_from_str(x), fe_from_str(y)).unwrap();
+        assert_eq!(hasher(message.as_ref()).to_affine(), expected);
+    }
+}
+
 pub fn svdw_map_to_curve_test<G: CurveExt>(
     z: G::Base,
     precomputed_constants: [&'static str; 4],
     test_vector: impl IntoIterator<Item = (&'static str, (&'static str, &'static str))>,
 ) where
-    <G as CurveExt>::Base: Legendre,
+    <G as CurveExt>::Base: Legendre + hash_to_curve::Sgn0,
 {
     let [c1, c2, c3, c4] = hash_to_curve::svdw_precomputed_constants::<G>(z);
     assert_eq!([c1, c2, c3, c4], precomputed_constants.map(fe_from_str));
@@ -6,8 +6,22 @@ use std::borrow::Cow;
 pub mod curve;
 pub mod field;
 
+/// Parses a field element from its decimal or `0x` prefixed hexadecimal value.
+///
+/// Elements of quadratic extensions are written as their two coefficients `"c0,c1"`, and
+/// built from a representation holding both of them side by side in little endian.
 pub(crate) fn fe_from_str<F: PrimeField>(string: impl AsRef<str>) -> F {
     let string = string.as_ref();
+    if let Some((c0, c1)) = string.split_once(',') {
+        let mut repr = F::Repr::default();
+        let len = repr.as_ref().len() / 2;
+        for (bytes, c) in repr.as_mut().chunks_mut(len).zip([c0, c1]) {
+            let c: F = fe_from_str(c.trim());
+            bytes.copy_from_slice(&c.to_repr().as_ref()[..len]);
+        }
+        return F::from_repr(repr).unwrap();
+    }
+
     let oct = if let Some(hex) = string.strip_prefix("0x") {
         Cow::Owned(BigUint::from_str_radix(hex, 16).unwrap().to_string())
     } else {